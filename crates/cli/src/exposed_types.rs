@@ -0,0 +1,422 @@
+use roc_collections::SendMap;
+use roc_error_macros::internal_error;
+use roc_load::LoadedModule;
+use roc_module::ident::{Lowercase, TagName};
+use roc_module::symbol::{Interns, Symbol};
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use roc_types::types::{AliasKind, ErrorType, Polarity, RecordField, TypeExt};
+use serde_json::{json, Value};
+
+/// The root module's exposed values and their types, as a JSON array like
+///
+/// ```json
+/// [
+///   {
+///     "name": "greet",
+///     "type": "Str -> Str",
+///     "structure": {
+///       "kind": "function",
+///       "arguments": [{ "kind": "apply", "module": "Str", "name": "Str", "arguments": [] }],
+///       "return": { "kind": "apply", "module": "Str", "name": "Str", "arguments": [] }
+///     }
+///   }
+/// ]
+/// ```
+///
+/// `type` is the type as `roc check` would print it, and `structure` is the same type
+/// as a tree, so tools can inspect a module's interface without linking against the
+/// compiler. Opaque types only show their name and arguments. Recursion variables the
+/// compiler generated have names starting with `#`.
+pub fn exposed_types_json(loaded: &LoadedModule) -> Value {
+    let home = loaded.module_id;
+    let interns = &loaded.interns;
+
+    // naming type variables writes to the subs, so work on a copy
+    let mut subs = loaded.solved.inner().clone();
+    let mut structure = Structure {
+        interns,
+        recursion_vars: Vec::new(),
+    };
+
+    let exposed = loaded
+        .exposes
+        .get(&home)
+        .into_iter()
+        .flatten()
+        .map(|(symbol, var)| {
+            // this names the type variables, so `structure` below uses the same names
+            let type_str = name_and_print_var(*var, &mut subs, home, interns, DebugPrint::NOTHING);
+            let error_type = subs.var_to_error_type(*var, Polarity::Pos);
+
+            json!({
+                "name": symbol.as_str(interns),
+                "type": type_str,
+                "structure": structure.describe(error_type),
+            })
+        })
+        .collect();
+
+    Value::Array(exposed)
+}
+
+/// Converts a type into a JSON tree, remembering the recursion variables of the
+/// recursive tag unions it is inside of.
+struct Structure<'a> {
+    interns: &'a Interns,
+    recursion_vars: Vec<Lowercase>,
+}
+
+impl<'a> Structure<'a> {
+    fn describe(&mut self, typ: ErrorType) -> Value {
+        use ErrorType::*;
+
+        let interns = self.interns;
+
+        match typ {
+            Type(symbol, arguments) => json!({
+                "kind": "apply",
+                "module": module_name(symbol, interns),
+                "name": symbol.as_str(interns),
+                "arguments": self.describe_all(arguments),
+            }),
+            Alias(symbol, arguments, real, kind) => {
+                let mut alias = json!({
+                    "kind": "alias",
+                    "module": module_name(symbol, interns),
+                    "name": symbol.as_str(interns),
+                    "arguments": self.describe_all(arguments),
+                    "opaque": matches!(kind, AliasKind::Opaque),
+                });
+
+                // the structure behind an opaque type is not part of the module's interface
+                if let AliasKind::Structural = kind {
+                    alias["type"] = self.describe(*real);
+                }
+
+                alias
+            }
+            Function(arguments, _closure, ret) => json!({
+                "kind": "function",
+                "arguments": self.describe_all(arguments),
+                "return": self.describe(*ret),
+            }),
+            Record(fields, ext) => {
+                let mut fields: Vec<_> = fields.into_iter().collect();
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                let fields: Vec<Value> = fields
+                    .into_iter()
+                    .map(|(label, field)| {
+                        let optional = matches!(
+                            field,
+                            RecordField::Optional(_) | RecordField::RigidOptional(_)
+                        );
+
+                        json!({
+                            "name": label.as_str(),
+                            "optional": optional,
+                            "type": self.describe(field.into_inner()),
+                        })
+                    })
+                    .collect();
+
+                json!({
+                    "kind": "record",
+                    "fields": fields,
+                    "extension": self.describe_ext(ext),
+                })
+            }
+            Tuple(mut elements, ext) => {
+                elements.sort_by_key(|(index, _)| *index);
+
+                json!({
+                    "kind": "tuple",
+                    "elements": self.describe_all(elements.into_iter().map(|(_, typ)| typ).collect()),
+                    "extension": self.describe_ext(ext),
+                })
+            }
+            TagUnion(tags, ext, _) => json!({
+                "kind": "tagUnion",
+                "tags": self.describe_tags(tags),
+                "extension": self.describe_ext(ext),
+            }),
+            RecursiveTagUnion(rec_var, tags, ext, _) => {
+                let rec_name = match *rec_var {
+                    FlexVar(name) | RigidVar(name) => name,
+                    other => internal_error!("recursion variable is not a variable: {other:?}"),
+                };
+
+                self.recursion_vars.push(rec_name.clone());
+
+                let union = json!({
+                    "kind": "recursiveTagUnion",
+                    "recursionVariable": self.describe_variable(&rec_name, &[]),
+                    "tags": self.describe_tags(tags),
+                    "extension": self.describe_ext(ext),
+                });
+
+                self.recursion_vars.pop();
+
+                union
+            }
+            FlexVar(name) | RigidVar(name) => self.describe_variable(&name, &[]),
+            FlexAbleVar(name, abilities) | RigidAbleVar(name, abilities) => {
+                let abilities: Vec<&str> = abilities
+                    .sorted_iter()
+                    .map(|ability| ability.as_str(interns))
+                    .collect();
+
+                self.describe_variable(&name, &abilities)
+            }
+            Range(types) => json!({
+                "kind": "range",
+                "types": self.describe_all(types),
+            }),
+            Infinite => json!({ "kind": "infinite" }),
+            Error => json!({ "kind": "error" }),
+        }
+    }
+
+    fn describe_all(&mut self, types: Vec<ErrorType>) -> Vec<Value> {
+        types.into_iter().map(|typ| self.describe(typ)).collect()
+    }
+
+    fn describe_tags(&mut self, tags: SendMap<TagName, Vec<ErrorType>>) -> Vec<Value> {
+        let mut tags: Vec<_> = tags.into_iter().collect();
+        tags.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        tags.into_iter()
+            .map(|(tag_name, payload)| {
+                json!({
+                    "name": tag_name.as_ident_str().as_str(),
+                    "payload": self.describe_all(payload),
+                })
+            })
+            .collect()
+    }
+
+    /// `null` for a closed record or tag union, otherwise the open extension variable.
+    fn describe_ext(&self, ext: TypeExt) -> Value {
+        match ext {
+            TypeExt::Closed => Value::Null,
+            TypeExt::FlexOpen(name) | TypeExt::RigidOpen(name) => {
+                self.describe_variable(&name, &[])
+            }
+        }
+    }
+
+    fn describe_variable(&self, name: &Lowercase, abilities: &[&str]) -> Value {
+        // Generated names start with a `#`. Recursion variables are referred to inside
+        // their union, so they keep that name; other generated variables appear only
+        // once, and print as `*`.
+        if name.as_str().starts_with('#') && !self.recursion_vars.contains(name) {
+            return json!({ "kind": "wildcard" });
+        }
+
+        json!({
+            "kind": "variable",
+            "name": name.as_str(),
+            "abilities": abilities,
+        })
+    }
+}
+
+fn module_name(symbol: Symbol, interns: &Interns) -> &str {
+    interns.module_name(symbol.module_id())
+}
+
+#[cfg(test)]
+mod test_exposed_types {
+    use super::exposed_types_json;
+    use bumpalo::Bump;
+    use indoc::indoc;
+    use roc_load::FunctionKind;
+    use roc_packaging::cache::RocCacheDir;
+    use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+    use roc_target::TargetInfo;
+    use serde_json::{json, Value};
+    use std::path::PathBuf;
+
+    fn exposed_types(src: &str) -> Value {
+        let arena = Bump::new();
+        let loaded = roc_load::load_and_typecheck_str(
+            &arena,
+            PathBuf::from("Test.roc"),
+            src,
+            PathBuf::from("."),
+            TargetInfo::default_x86_64(),
+            FunctionKind::LambdaSet,
+            RenderTarget::Generic,
+            RocCacheDir::Disallowed,
+            DEFAULT_PALETTE,
+        );
+
+        match loaded {
+            Ok(loaded) => exposed_types_json(&loaded),
+            Err(_) => panic!("failed to load:\n{src}"),
+        }
+    }
+
+    fn variable(name: &str) -> Value {
+        json!({ "kind": "variable", "name": name, "abilities": [] })
+    }
+
+    fn str_type() -> Value {
+        json!({ "kind": "apply", "module": "Str", "name": "Str", "arguments": [] })
+    }
+
+    #[test]
+    fn function() {
+        let exposed = exposed_types(indoc!(
+            r"
+            interface Test exposes [apply] imports []
+
+            apply : (a -> b), a -> b
+            apply = \f, x -> f x
+            "
+        ));
+
+        assert_eq!(
+            exposed,
+            json!([{
+                "name": "apply",
+                "type": "(a -> b), a -> b",
+                "structure": {
+                    "kind": "function",
+                    "arguments": [
+                        {
+                            "kind": "function",
+                            "arguments": [variable("a")],
+                            "return": variable("b"),
+                        },
+                        variable("a"),
+                    ],
+                    "return": variable("b"),
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn record() {
+        let exposed = exposed_types(indoc!(
+            r#"
+            interface Test exposes [greet] imports []
+
+            greet : { name : Str, greeting ? Str } -> Str
+            greet = \{ name, greeting ? "Hello, " } -> Str.concat greeting name
+            "#
+        ));
+
+        assert_eq!(
+            exposed,
+            json!([{
+                "name": "greet",
+                "type": "{ greeting ? Str, name : Str } -> Str",
+                "structure": {
+                    "kind": "function",
+                    "arguments": [{
+                        "kind": "record",
+                        "fields": [
+                            { "name": "greeting", "optional": true, "type": str_type() },
+                            { "name": "name", "optional": false, "type": str_type() },
+                        ],
+                        "extension": null,
+                    }],
+                    "return": str_type(),
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn tag_union() {
+        let exposed = exposed_types(indoc!(
+            r#"
+            interface Test exposes [red] imports []
+
+            red = Red "ff0000"
+            "#
+        ));
+
+        assert_eq!(
+            exposed,
+            json!([{
+                "name": "red",
+                "type": "[Red Str]",
+                "structure": {
+                    "kind": "tagUnion",
+                    "tags": [{ "name": "Red", "payload": [str_type()] }],
+                    "extension": { "kind": "wildcard" },
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn recursive_tag_union_alias() {
+        let exposed = exposed_types(indoc!(
+            r"
+            interface Test exposes [ConsList, empty] imports []
+
+            ConsList a : [Cons a (ConsList a), Nil]
+
+            empty : ConsList Str
+            empty = Nil
+            "
+        ));
+
+        assert_eq!(
+            exposed,
+            json!([{
+                "name": "empty",
+                "type": "ConsList Str",
+                "structure": {
+                    "kind": "alias",
+                    "module": "Test",
+                    "name": "ConsList",
+                    "arguments": [str_type()],
+                    "opaque": false,
+                    "type": {
+                        "kind": "recursiveTagUnion",
+                        "recursionVariable": variable("#a"),
+                        "tags": [
+                            { "name": "Cons", "payload": [str_type(), variable("#a")] },
+                            { "name": "Nil", "payload": [] },
+                        ],
+                        "extension": { "kind": "wildcard" },
+                    },
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn opaque() {
+        let exposed = exposed_types(indoc!(
+            r"
+            interface Test exposes [Id, zero] imports []
+
+            Id := U64
+
+            zero : Id
+            zero = @Id 0
+            "
+        ));
+
+        assert_eq!(
+            exposed,
+            json!([{
+                "name": "zero",
+                "type": "Id",
+                "structure": {
+                    "kind": "alias",
+                    "module": "Test",
+                    "name": "Id",
+                    "arguments": [],
+                    "opaque": true,
+                },
+            }])
+        );
+    }
+}
@@ -8,11 +8,7 @@ use roc_fmt::def::fmt_defs;
 use roc_fmt::module::fmt_module;
 use roc_fmt::spaces::RemoveSpaces;
 use roc_fmt::{Ast, Buf};
-use roc_parse::{
-    module::{self, module_defs},
-    parser::{Parser, SyntaxError},
-    state::State,
-};
+use roc_parse::{module::parse_module, parser::SyntaxError};
 
 #[derive(Copy, Clone, Debug)]
 pub enum FormatMode {
@@ -225,10 +221,7 @@ pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
 }
 
 fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
-    let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
-        .map_err(|e| SyntaxError::Header(e.problem))?;
-
-    let (_, defs, _) = module_defs().parse(arena, state, 0).map_err(|(_, e)| e)?;
+    let (module, defs) = parse_module(arena, src)?;
 
     Ok(Ast { module, defs })
 }
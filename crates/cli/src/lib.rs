@@ -37,7 +37,9 @@ use target_lexicon::{Architecture, Triple};
 #[cfg(not(target_os = "linux"))]
 use tempfile::TempDir;
 
+mod exposed_types;
 mod format;
+pub use exposed_types::exposed_types_json;
 pub use format::{format_files, format_src, FormatMode};
 
 pub const CMD_BUILD: &str = "build";
@@ -54,6 +56,7 @@ pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
+pub const FLAG_EMIT_ASM: &str = "emit-asm";
 pub const FLAG_PROFILING: &str = "profiling";
 pub const FLAG_BUNDLE: &str = "bundle";
 pub const FLAG_DEV: &str = "dev";
@@ -73,6 +76,9 @@ pub const FLAG_STDOUT: &str = "stdout";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
 pub const FLAG_FUZZ: &str = "fuzz";
+pub const FLAG_JSON: &str = "json";
+pub const FLAG_FILTER: &str = "filter";
+pub const FLAG_EMIT_EXPOSED_TYPES: &str = "emit-exposed-types";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_DIR: &str = "GLUE_DIR";
@@ -114,6 +120,12 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_emit_asm = Arg::new(FLAG_EMIT_ASM)
+        .long(FLAG_EMIT_ASM)
+        .help("Emit a `.s` file containing the target assembly of the program")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let flag_profiling = Arg::new(FLAG_PROFILING)
         .long(FLAG_PROFILING)
         .help("Keep debug info in the final generated program even in optimized builds")
@@ -182,6 +194,7 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -233,6 +246,7 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -245,6 +259,20 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false)
             )
+            .arg(
+                Arg::new(FLAG_FILTER)
+                    .long(FLAG_FILTER)
+                    .help("Only run the top-level `expect`s whose module name or source code contains this text")
+                    .value_parser(value_parser!(String))
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_JSON)
+                    .long(FLAG_JSON)
+                    .help("Print the test results as a JSON object instead of human-readable reports")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -264,6 +292,7 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -279,6 +308,7 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -324,6 +354,20 @@ pub fn build_app() -> Command {
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_JSON)
+                    .long(FLAG_JSON)
+                    .help("Print the problems found as a JSON array instead of human-readable reports")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_EMIT_EXPOSED_TYPES)
+                    .long(FLAG_EMIT_EXPOSED_TYPES)
+                    .help("Write the module's exposed values and their types to this file as JSON")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -411,6 +455,7 @@ pub fn build_app() -> Command {
         .arg(flag_opt_size)
         .arg(flag_dev)
         .arg(flag_emit_llvm_ir)
+        .arg(flag_emit_asm)
         .arg(flag_profiling)
         .arg(flag_time)
         .arg(flag_linker)
@@ -456,8 +501,11 @@ struct ModuleTestResults {
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     use roc_build::program::report_problems_monomorphized;
-    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError};
+    use roc_load::{
+        ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, LoadingProblem,
+    };
     use roc_packaging::cache;
+    use roc_region::all::LineInfo;
     use roc_target::TargetInfo;
 
     let start_time = Instant::now();
@@ -500,12 +548,19 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     // TODO may need to determine this dynamically based on dev builds.
     let function_kind = FunctionKind::LambdaSet;
 
+    let json = matches.get_flag(FLAG_JSON);
+    let filter = matches.get_one::<String>(FLAG_FILTER);
+
     // Step 1: compile the app and generate the .o file
     let load_config = LoadConfig {
         target_info,
         function_kind,
-        // TODO: expose this from CLI?
-        render: roc_reporting::report::RenderTarget::ColorTerminal,
+        // JSON output can't contain color codes, so load errors are rendered without them
+        render: if json {
+            roc_reporting::report::RenderTarget::Generic
+        } else {
+            roc_reporting::report::RenderTarget::ColorTerminal
+        },
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Test,
@@ -519,14 +574,56 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
 
     let mut loaded = match load_result {
         Ok(loaded) => loaded,
+        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(
+            report,
+            summary,
+        ))) if json => {
+            let problems_json = vec![roc_reporting::cli::loading_problem_to_json(
+                &report,
+                summary.as_ref(),
+            )];
+
+            print_test_summary_json(problems_json, Vec::new(), 0, 0, start_time.elapsed());
+
+            return Ok(1);
+        }
         Err(LoadMonomorphizedError::LoadingProblem(problem)) => {
             return handle_loading_problem(problem);
         }
+        Err(LoadMonomorphizedError::ErrorModule(mut module)) if json => {
+            let problems_json = roc_reporting::cli::problems_to_json(
+                &module.sources,
+                &module.interns,
+                &mut module.can_problems,
+                &mut module.type_problems,
+            );
+            let problems = roc_reporting::cli::count_json_problems(&problems_json);
+
+            print_test_summary_json(problems_json, Vec::new(), 0, 0, start_time.elapsed());
+
+            return Ok(problems.exit_code());
+        }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
             return handle_error_module(module, start_time.elapsed(), path.as_os_str(), false);
         }
     };
-    let problems = report_problems_monomorphized(&mut loaded);
+
+    // In JSON mode, warnings go into the summary instead of being printed as reports.
+    let (problems, problems_json) = if json {
+        let problems_json = roc_reporting::cli::problems_to_json(
+            &loaded.sources,
+            &loaded.interns,
+            &mut loaded.can_problems,
+            &mut loaded.type_problems,
+        );
+
+        (
+            roc_reporting::cli::count_json_problems(&problems_json),
+            problems_json,
+        )
+    } else {
+        (report_problems_monomorphized(&mut loaded), Vec::new())
+    };
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
 
@@ -549,7 +646,7 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             problems.errors, 0,
             "if there were errors, we would have already exited."
         );
-        if problems.warnings > 0 {
+        if problems.warnings > 0 && !json {
             problems.print_error_warning_count(start_time.elapsed());
             println!(".\n\nRunning tests…\n\n\x1B[36m{}\x1B[39m", "─".repeat(80));
         }
@@ -565,24 +662,55 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     let mut total_passed_count = 0;
 
     let mut results_by_module = Vec::new();
+    let mut test_results_json = Vec::new();
     let global_layout_interner = layout_interner.into_global();
 
     let compilation_duration = start_time.elapsed();
 
-    for (module_id, expects) in expects_by_module.into_iter() {
+    for (module_id, mut expects) in expects_by_module.into_iter() {
+        let module_name = interns.module_name(module_id).as_str();
+        let (module_path, source) = sources.get(&module_id).unwrap();
+
+        if let Some(filter) = filter {
+            retain_matching_expects(&mut expects, module_name, source, filter);
+        }
+
         let test_start_time = Instant::now();
 
-        let (failed_count, passed_count) = roc_repl_expect::run::run_toplevel_expects(
-            &mut writer,
-            roc_reporting::report::RenderTarget::ColorTerminal,
-            arena,
-            interns,
-            &global_layout_interner,
-            &dyn_lib,
-            &mut expectations,
-            expects,
-        )
-        .unwrap();
+        let (failed_count, passed_count) = if json {
+            let results = roc_repl_expect::run::run_toplevel_expects_with_results(
+                roc_reporting::report::RenderTarget::Generic,
+                arena,
+                interns,
+                &global_layout_interner,
+                &dyn_lib,
+                &mut expectations,
+                expects,
+            )
+            .unwrap();
+
+            let failed_count = results.iter().filter(|result| !result.passed).count();
+            let passed_count = results.len() - failed_count;
+            let line_info = LineInfo::new(source);
+
+            test_results_json.extend(results.into_iter().map(|result| {
+                expect_result_to_json(result, module_name, module_path, source, &line_info)
+            }));
+
+            (failed_count, passed_count)
+        } else {
+            roc_repl_expect::run::run_toplevel_expects(
+                &mut writer,
+                roc_reporting::report::RenderTarget::ColorTerminal,
+                arena,
+                interns,
+                &global_layout_interner,
+                &dyn_lib,
+                &mut expectations,
+                expects,
+            )
+            .unwrap()
+        };
 
         let tests_duration = test_start_time.elapsed();
 
@@ -599,6 +727,22 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
 
     let total_duration = start_time.elapsed();
 
+    if json {
+        print_test_summary_json(
+            problems_json,
+            test_results_json,
+            total_failed_count,
+            total_passed_count,
+            total_duration,
+        );
+
+        // As below, running no tests at all is treated as an error.
+        return Ok(match (total_failed_count, total_passed_count) {
+            (0, 0) => 2,
+            (failed, _) => (failed > 0) as i32,
+        });
+    }
+
     if total_failed_count == 0 && total_passed_count == 0 {
         // TODO print this in a more nicely formatted way!
         println!("No expectations were found.");
@@ -625,6 +769,101 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     }
 }
 
+/// Keeps only the expects whose module name or source code contains `filter`.
+#[cfg(not(windows))]
+fn retain_matching_expects(
+    expects: &mut roc_repl_expect::run::ExpectFunctions,
+    module_name: &str,
+    source: &str,
+    filter: &str,
+) {
+    if module_name.contains(filter) {
+        return;
+    }
+
+    let matches = |expect: &roc_repl_expect::run::ToplevelExpect| {
+        let region = expect.region;
+        source[region.start().offset as usize..region.end().offset as usize].contains(filter)
+    };
+
+    expects.pure.retain(|expect| matches(expect));
+    expects.fx.retain(|expect| matches(expect));
+}
+
+/// Prints the `roc test --json` summary. When the module failed to compile,
+/// `problems` says why and there are no `tests`.
+#[cfg(not(windows))]
+fn print_test_summary_json(
+    problems: Vec<serde_json::Value>,
+    tests: Vec<serde_json::Value>,
+    failed: usize,
+    passed: usize,
+    duration: Duration,
+) {
+    let summary = serde_json::json!({
+        "problems": problems,
+        "tests": tests,
+        "failed": failed,
+        "passed": passed,
+        "duration_ms": duration.as_secs_f64() * 1000.0,
+    });
+
+    println!("{summary}");
+}
+
+/// Converts the outcome of one expect into a JSON object like
+///
+/// ```json
+/// {
+///   "module": "Main",
+///   "file": "main.roc",
+///   "region": { "start": { "line": 4, "column": 0 }, "end": { "line": 4, "column": 17 } },
+///   "source": "expect 1 + 1 == 3",
+///   "status": "failed",
+///   "duration_ms": 0.012,
+///   "message": "── EXPECT FAILED in main.roc ──..."
+/// }
+/// ```
+///
+/// Lines and columns are zero-based. `message` is `null` for expects that passed.
+#[cfg(not(windows))]
+fn expect_result_to_json(
+    result: roc_repl_expect::run::ToplevelExpectResult,
+    module_name: &str,
+    module_path: &Path,
+    source: &str,
+    line_info: &roc_region::all::LineInfo,
+) -> serde_json::Value {
+    use roc_region::all::LineColumnRegion;
+
+    let region = result.region;
+    let source_text = &source[region.start().offset as usize..region.end().offset as usize];
+    let LineColumnRegion { start, end } = line_info.convert_region(region);
+
+    let message = if result.passed {
+        None
+    } else {
+        Some(
+            String::from_utf8_lossy(&result.output)
+                .trim_end()
+                .to_string(),
+        )
+    };
+
+    serde_json::json!({
+        "module": module_name,
+        "file": module_path.display().to_string(),
+        "region": {
+            "start": { "line": start.line, "column": start.column },
+            "end": { "line": end.line, "column": end.column },
+        },
+        "source": source_text,
+        "status": if result.passed { "passed" } else { "failed" },
+        "duration_ms": result.duration.as_secs_f64() * 1000.0,
+        "message": message,
+    })
+}
+
 fn print_test_results(
     module_test_results: ModuleTestResults,
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
@@ -803,6 +1042,14 @@ pub fn build(
         user_error!("Cannot emit llvm ir while using a dev backend.");
     }
 
+    let emit_asm = matches.get_flag(FLAG_EMIT_ASM);
+    if emit_asm && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot emit assembly while using a dev backend.");
+    }
+    if emit_asm && matches!(triple.architecture, Architecture::Wasm32) {
+        user_error!("Cannot emit assembly when building for wasm32.");
+    }
+
     let emit_debug_info = matches.get_flag(FLAG_PROFILING)
         || matches!(opt_level, OptLevel::Development | OptLevel::Normal);
     let emit_timings = matches.get_flag(FLAG_TIME);
@@ -859,6 +1106,7 @@ pub fn build(
         opt_level,
         emit_debug_info,
         emit_llvm_ir,
+        emit_asm,
         fuzz,
     };
 
@@ -3,11 +3,11 @@ use bumpalo::Bump;
 use roc_build::link::LinkType;
 use roc_build::program::{check_file, CodeGenBackend};
 use roc_cli::{
-    build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
-    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL,
-    CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB,
-    FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR,
-    GLUE_SPEC, ROC_FILE,
+    build_app, exposed_types_json, format_files, format_src, test, BuildConfig, FormatMode,
+    CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE,
+    CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK,
+    FLAG_DEV, FLAG_EMIT_EXPOSED_TYPES, FLAG_LIB, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN,
+    FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR, GLUE_SPEC, ROC_FILE,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
@@ -194,6 +194,7 @@ fn main() -> io::Result<()> {
             let arena = Bump::new();
 
             let emit_timings = matches.get_flag(FLAG_TIME);
+            let report_json = matches.get_flag(roc_cli::FLAG_JSON);
             let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
                 None => Threading::AllAvailable,
@@ -206,16 +207,35 @@ fn main() -> io::Result<()> {
                 &arena,
                 roc_file_path.to_owned(),
                 emit_timings,
+                report_json,
                 RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
                 threading,
             ) {
-                Ok((problems, total_time)) => {
-                    problems.print_error_warning_count(total_time);
+                Ok((problems, total_time, loaded)) => {
+                    if !report_json {
+                        problems.print_error_warning_count(total_time);
+                    }
+
+                    if let Some(path) = matches.get_one::<PathBuf>(FLAG_EMIT_EXPOSED_TYPES) {
+                        let json = exposed_types_json(&loaded).to_string();
+
+                        if let Err(err) = fs::write(path, json) {
+                            user_error!("could not write {}: {}", path.display(), err);
+                        }
+                    }
+
                     Ok(problems.exit_code())
                 }
 
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{report}");
+                Err(LoadingProblem::FormattedReport(report, summary)) => {
+                    if report_json {
+                        let diagnostic =
+                            roc_reporting::cli::loading_problem_to_json(&report, summary.as_ref());
+
+                        println!("{}", serde_json::Value::Array(vec![diagnostic]));
+                    } else {
+                        print!("{report}");
+                    }
 
                     Ok(1)
                 }
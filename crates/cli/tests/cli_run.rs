@@ -55,7 +55,9 @@ mod cli_run {
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT);
-    #[allow(dead_code)]
+    const EMIT_EXPOSED_TYPES_FLAG: &str = concatcp!("--", roc_cli::FLAG_EMIT_EXPOSED_TYPES);
+    const EMIT_ASM_FLAG: &str = concatcp!("--", roc_cli::FLAG_EMIT_ASM);
+    const JSON_FLAG: &str = concatcp!("--", roc_cli::FLAG_JSON);
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
 
     #[derive(Debug)]
@@ -661,6 +663,54 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn expects_json() {
+        let out = run_roc(
+            [
+                CMD_TEST,
+                file_path_from_root("crates/cli/tests/expects_json", "Arith.roc")
+                    .to_str()
+                    .unwrap(),
+                JSON_FLAG,
+            ],
+            &[],
+            &[],
+        );
+        assert_eq!(out.status.code(), Some(1));
+
+        let summary: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+
+        assert_eq!(summary["problems"], serde_json::json!([]));
+        assert_eq!(summary["failed"], 1);
+        assert_eq!(summary["passed"], 1);
+
+        let mut tests: Vec<_> = summary["tests"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|test| {
+                assert_eq!(test["module"], "Arith");
+                assert_eq!(test["message"].is_null(), test["status"] == "passed");
+
+                (
+                    test["region"]["start"]["line"].as_u64().unwrap(),
+                    test["source"].as_str().unwrap(),
+                    test["status"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        tests.sort();
+
+        assert_eq!(
+            tests,
+            [
+                (6, "expect add 1 2 == 3", "passed"),
+                (8, "expect add 2 2 == 5", "failed"),
+            ]
+        );
+    }
+
     #[test]
     #[cfg_attr(windows, ignore)]
     fn transitive_expects_verbose() {
@@ -682,6 +732,21 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn transitive_expects_filter() {
+        test_roc_expect(
+            "crates/cli/tests/expects_transitive",
+            "main.roc",
+            &["--filter", "Transitive"],
+            indoc!(
+                r#"
+                0 failed and 1 passed in <ignored for test> ms.
+                "#
+            ),
+        );
+    }
+
     #[test]
     #[cfg_attr(
         windows,
@@ -1327,6 +1392,40 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[serial(multi_dep_str)]
+    #[cfg_attr(windows, ignore)]
+    fn build_emit_asm() {
+        let file = fixture_file("multi-dep-str", "Main.roc");
+        let asm_file = file.with_extension("s");
+
+        let out = run_roc([CMD_BUILD, file.to_str().unwrap(), EMIT_ASM_FLAG], &[], &[]);
+        assert!(out.status.success(), "{}", out.stderr);
+
+        let asm = std::fs::read_to_string(&asm_file).unwrap();
+        std::fs::remove_file(&asm_file).unwrap();
+
+        assert!(!asm.trim().is_empty());
+    }
+
+    #[test]
+    fn emit_asm_for_wasm32_is_rejected() {
+        let out = run_roc(
+            [
+                CMD_BUILD,
+                fixture_file("multi-dep-str", "Main.roc").to_str().unwrap(),
+                EMIT_ASM_FLAG,
+                concatcp!(TARGET_FLAG, "=wasm32"),
+            ],
+            &[],
+            &[],
+        );
+        assert_eq!(out.status.code(), Some(1));
+        assert!(out
+            .stderr
+            .contains("Cannot emit assembly when building for wasm32."));
+    }
+
     #[test]
     #[serial(multi_dep_thunk)]
     #[cfg_attr(windows, ignore)]
@@ -1430,6 +1529,62 @@ mod cli_run {
         );
     }
 
+    #[test]
+    fn check_emit_exposed_types() {
+        let out_dir = tempfile::tempdir().unwrap();
+        let out_file = out_dir.path().join("exposed.json");
+
+        let out = run_roc(
+            [
+                CMD_CHECK,
+                fixture_file("multi-dep-str", "Dep1.roc").to_str().unwrap(),
+                EMIT_EXPOSED_TYPES_FLAG,
+                out_file.to_str().unwrap(),
+            ],
+            &[],
+            &[],
+        );
+        assert!(out.status.success());
+
+        let exposed: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&out_file).unwrap()).unwrap();
+
+        assert_eq!(
+            exposed,
+            serde_json::json!([{
+                "name": "str1",
+                "type": "Str",
+                "structure": { "kind": "apply", "module": "Str", "name": "Str", "arguments": [] },
+            }])
+        );
+    }
+
+    #[test]
+    fn check_json_syntax_error() {
+        let out = run_roc(
+            [
+                CMD_CHECK,
+                known_bad_file("SyntaxError.roc").to_str().unwrap(),
+                JSON_FLAG,
+            ],
+            &[],
+            &[],
+        );
+        assert_eq!(out.status.code(), Some(1));
+
+        let diagnostics: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+        let diagnostics = diagnostics.as_array().unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["severity"], "fatal");
+        assert_eq!(diagnostics[0]["code"], "syntax-error");
+        assert_eq!(diagnostics[0]["title"], "UNFINISHED IF");
+        assert!(diagnostics[0]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("SyntaxError.roc"));
+    }
+
     #[test]
     fn known_type_error_with_long_path() {
         check_compile_error(
@@ -1464,6 +1619,9 @@ mod cli_run {
 
                 bar is listed as exposed, but it isn't defined in this module.
 
+                2│      exposes [bar]
+                                 ^^^
+
                 You can fix this by adding a definition for bar, or by removing it
                 from exposes.
 
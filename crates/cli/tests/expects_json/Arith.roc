@@ -0,0 +1,9 @@
+interface Arith
+    exposes [add]
+    imports []
+
+add = \num1, num2 -> num1 + num2
+
+expect add 1 2 == 3
+
+expect add 2 2 == 5
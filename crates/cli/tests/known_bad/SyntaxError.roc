@@ -0,0 +1,5 @@
+interface SyntaxError
+    exposes [main]
+    imports []
+
+main = if x then 1
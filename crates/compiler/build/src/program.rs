@@ -14,7 +14,7 @@ use roc_load::{
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::{
-    cli::{report_problems, Problems},
+    cli::{report_problems, report_problems_json, Problems},
     report::{RenderTarget, DEFAULT_PALETTE},
 };
 use roc_target::{OperatingSystem, TargetInfo};
@@ -57,6 +57,15 @@ pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
     )
 }
 
+pub fn report_problems_typechecked_json(loaded: &mut LoadedModule) -> Problems {
+    report_problems_json(
+        &loaded.sources,
+        &loaded.interns,
+        &mut loaded.can_problems,
+        &mut loaded.type_problems,
+    )
+}
+
 pub enum CodeObject {
     MemoryBuffer(MemoryBuffer),
     Vector(Vec<u8>),
@@ -86,6 +95,7 @@ pub struct CodeGenOptions {
     pub opt_level: OptLevel,
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
+    pub emit_asm: bool,
     pub fuzz: bool,
 }
 
@@ -104,6 +114,7 @@ pub fn gen_from_mono_module<'a>(
     let path = roc_file_path;
     let debug = code_gen_options.emit_debug_info;
     let emit_llvm_ir = code_gen_options.emit_llvm_ir;
+    let emit_asm = code_gen_options.emit_asm;
     let fuzz = code_gen_options.fuzz;
     let opt = code_gen_options.opt_level;
 
@@ -133,6 +144,7 @@ pub fn gen_from_mono_module<'a>(
             backend_mode,
             debug,
             emit_llvm_ir,
+            emit_asm,
             fuzz,
         ),
     }
@@ -151,6 +163,7 @@ fn gen_from_mono_module_llvm<'a>(
     backend_mode: LlvmBackendMode,
     emit_debug_info: bool,
     emit_llvm_ir: bool,
+    emit_asm: bool,
     fuzz: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
@@ -173,6 +186,8 @@ fn gen_from_mono_module_llvm<'a>(
         roc_file_path_buf
     };
 
+    let app_s_file = app_ll_file.with_extension("s");
+
     let kind_id = Attribute::get_named_enum_kind_id("alwaysinline");
     debug_assert!(kind_id > 0);
     let enum_attr = context.create_enum_attribute(kind_id, 0);
@@ -379,6 +394,23 @@ fn gen_from_mono_module_llvm<'a>(
             "{bc_to_object_output:#?}"
         );
 
+        if emit_asm {
+            eprintln!("Emitting assembly to {}", &app_s_file.display());
+
+            let bc_to_asm_output = Command::new("llc")
+                .args([
+                    "-relocation-model=pic",
+                    "-filetype=asm",
+                    &temp_app_processed_file_str,
+                    "-o",
+                    app_s_file.to_str().unwrap(),
+                ])
+                .output()
+                .unwrap();
+
+            assert!(bc_to_asm_output.status.success(), "{bc_to_asm_output:#?}");
+        }
+
         MemoryBuffer::create_from_file(&temp_app_o_file).expect("memory buffer creation works")
     } else {
         if emit_llvm_ir {
@@ -394,6 +426,14 @@ fn gen_from_mono_module_llvm<'a>(
                 let target_machine =
                     target::target_machine(target, convert_opt_level(opt_level), reloc).unwrap();
 
+                if emit_asm {
+                    eprintln!("Emitting assembly to {}", &app_s_file.display());
+
+                    target_machine
+                        .write_to_file(env.module, FileType::Assembly, &app_s_file)
+                        .expect("Writing .s file failed");
+                }
+
                 target_machine
                     .write_to_memory_buffer(env.module, FileType::Object)
                     .expect("Writing .o file failed")
@@ -680,7 +720,7 @@ pub fn handle_error_module(
 
 pub fn handle_loading_problem(problem: LoadingProblem) -> std::io::Result<i32> {
     match problem {
-        LoadingProblem::FormattedReport(report) => {
+        LoadingProblem::FormattedReport(report, _) => {
             print!("{report}");
             Ok(1)
         }
@@ -1220,9 +1260,10 @@ pub fn check_file<'a>(
     arena: &'a Bump,
     roc_file_path: PathBuf,
     emit_timings: bool,
+    report_json: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
-) -> Result<(Problems, Duration), LoadingProblem<'a>> {
+) -> Result<(Problems, Duration, LoadedModule), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
     // only used for generating errors. We don't do code generation, so hardcoding should be fine
@@ -1235,8 +1276,12 @@ pub fn check_file<'a>(
         target_info,
         // TODO: we may not want this for just checking.
         function_kind: FunctionKind::LambdaSet,
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
+        // JSON output can't contain color codes, so load errors are rendered without them
+        render: if report_json {
+            RenderTarget::Generic
+        } else {
+            RenderTarget::ColorTerminal
+        },
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
@@ -1279,14 +1324,26 @@ pub fn check_file<'a>(
     let compilation_end = compilation_start.elapsed();
 
     if emit_timings {
-        println!(
-            "\n\nCompilation finished!\n\nHere's how long each module took to compile:\n\n{buf}"
+        let timings = format!(
+            "\n\nCompilation finished!\n\nHere's how long each module took to compile:\n\n{buf}\nFinished checking in {} ms\n",
+            compilation_end.as_millis(),
         );
 
-        println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
+        // with --json, stdout must only contain the JSON
+        if report_json {
+            eprintln!("{timings}");
+        } else {
+            println!("{timings}");
+        }
     }
 
-    Ok((report_problems_typechecked(&mut loaded), compilation_end))
+    let problems = if report_json {
+        report_problems_typechecked_json(&mut loaded)
+    } else {
+        report_problems_typechecked(&mut loaded)
+    };
+
+    Ok((problems, compilation_end, loaded))
 }
 
 pub fn build_str_test<'a>(
@@ -1302,6 +1359,7 @@ pub fn build_str_test<'a>(
         opt_level: OptLevel::Normal,
         emit_debug_info: false,
         emit_llvm_ir: false,
+        emit_asm: false,
         fuzz: false,
     };
 
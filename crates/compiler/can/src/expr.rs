@@ -1442,6 +1442,14 @@ pub fn canonicalize_expr<'a>(
             );
         }
         ast::Expr::Suffixed(_) => todo!(),
+        ast::Expr::TrySuffixed(_) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = MisplacedTrySuffix(region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
     };
 
     // At the end, diff used_idents and defined_idents to see which were unused.
@@ -2454,6 +2462,7 @@ pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
         | ast::Expr::Closure(_, loc_expr) => is_valid_interpolation(&loc_expr.value),
         ast::Expr::TupleAccess(sub_expr, _)
         | ast::Expr::ParensAround(sub_expr)
+        | ast::Expr::TrySuffixed(sub_expr)
         | ast::Expr::RecordAccess(sub_expr, _) => is_valid_interpolation(sub_expr),
         ast::Expr::Apply(loc_expr, args, _called_via) => {
             is_valid_interpolation(&loc_expr.value)
@@ -17,7 +17,7 @@ use roc_module::symbol::{IdentIds, IdentIdsByModule, ModuleId, ModuleIds, Symbol
 use roc_parse::ast::{Defs, TypeAnnotation};
 use roc_parse::header::HeaderType;
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{Problem, RuntimeError};
+use roc_problem::can::{Problem, RuntimeError, ShadowKind};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
 use roc_types::types::{AbilitySet, Alias, AliasKind, AliasVar, Type};
@@ -282,7 +282,7 @@ pub fn canonicalize_module_defs<'a>(
     dep_idents: &'a IdentIdsByModule,
     aliases: MutMap<Symbol, Alias>,
     imported_abilities_state: PendingAbilitiesStore,
-    exposed_imports: MutMap<Ident, (Symbol, Region)>,
+    exposed_imports: Vec<(Ident, (Symbol, Region))>,
     exposed_symbols: VecSet<Symbol>,
     symbols_from_requires: &[(Loc<Symbol>, Loc<TypeAnnotation<'a>>)],
     var_store: &mut VarStore,
@@ -331,7 +331,7 @@ pub fn canonicalize_module_defs<'a>(
         let first_char = ident.as_inline_str().as_str().chars().next().unwrap();
 
         if first_char.is_lowercase() {
-            match scope.import(ident, symbol, region) {
+            match scope.import(ident.clone(), symbol, region) {
                 Ok(()) => {
                     // Add an entry to exposed_imports using the current module's name
                     // as the key; e.g. if this is the Foo module and we have
@@ -339,8 +339,16 @@ pub fn canonicalize_module_defs<'a>(
                     // anything references `baz` in this Foo module, it will resolve to Bar.baz.
                     can_exposed_imports.insert(symbol, region);
                 }
-                Err((_shadowed_symbol, _region)) => {
-                    internal_error!("TODO gracefully handle shadowing in imports.")
+                Err((shadowed_symbol, original_region)) => {
+                    // Importing the same value twice is harmless; importing two
+                    // different values under one name is not.
+                    if shadowed_symbol != symbol {
+                        env.problem(Problem::Shadowing {
+                            original_region,
+                            shadow: Loc::at(region, ident),
+                            kind: ShadowKind::Variable,
+                        });
+                    }
                 }
             }
         } else {
@@ -354,15 +362,24 @@ pub fn canonicalize_module_defs<'a>(
 
             // but now we know this symbol by a different identifier, so we still need to add it to
             // the scope
-            match scope.import(ident, symbol, region) {
+            match scope.import(ident.clone(), symbol, region) {
                 Ok(()) => {
                     // here we do nothing special
                 }
-                Err((shadowed_symbol, _region)) => {
-                    internal_error!(
-                        "TODO gracefully handle shadowing in imports, {:?} is shadowed.",
-                        shadowed_symbol
-                    )
+                Err((shadowed_symbol, original_region)) => {
+                    if shadowed_symbol != symbol {
+                        let kind = if scope.abilities_store.is_ability(shadowed_symbol) {
+                            ShadowKind::Ability(shadowed_symbol)
+                        } else {
+                            ShadowKind::Alias(shadowed_symbol)
+                        };
+
+                        env.problem(Problem::Shadowing {
+                            original_region,
+                            shadow: Loc::at(region, ident),
+                            kind,
+                        });
+                    }
                 }
             }
         }
@@ -678,7 +695,14 @@ pub fn canonicalize_module_defs<'a>(
     // not, that means they were declared as exposed but there was
     // no actual declaration with that name!
     for symbol in exposed_but_not_defined {
-        env.problem(Problem::ExposedButNotDefined(symbol));
+        let ident = scope.locals.ident_ids.get_name(symbol.ident_id());
+        let region = header_type
+            .exposed_or_provided_values()
+            .iter()
+            .find(|loc_exposed| Some(loc_exposed.value.as_str()) == ident)
+            .map(|loc_exposed| loc_exposed.region);
+
+        env.problem(Problem::ExposedButNotDefined(symbol, region));
 
         // In case this exposed value is referenced by other modules,
         // create a decl for it whose implementation is a runtime error.
@@ -8,7 +8,7 @@ use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
 use roc_parse::ast::{
-    AssignedField, Collection, Pattern, RecordBuilderField, StrLiteral, StrSegment, ValueDef,
+    AssignedField, Collection, Defs, Pattern, RecordBuilderField, StrLiteral, StrSegment, ValueDef,
     WhenBranch,
 };
 use roc_region::all::{LineInfo, Loc, Region};
@@ -362,6 +362,11 @@ pub fn desugar_expr<'a>(
             module_path,
         ),
         Defs(defs, loc_ret) => {
+            if let Some(when_expr) = desugar_try_suffixed_def(arena, loc_expr.region, defs, loc_ret)
+            {
+                return desugar_expr(arena, when_expr, src, line_info, module_path);
+            }
+
             let mut defs = (*defs).clone();
             desugar_defs(arena, &mut defs, src, line_info, module_path);
 
@@ -620,9 +625,481 @@ pub fn desugar_expr<'a>(
         }
         LowLevelDbg(_, _, _) => unreachable!("Only exists after desugaring"),
         Suffixed(_) => todo!(),
+        // A `?` that is the whole body of a def got rewritten along with its block;
+        // any other `?` is reported as misplaced during canonicalization.
+        TrySuffixed(sub_expr) => {
+            let loc_sub_expr = arena.alloc(Loc::at(loc_expr.region, **sub_expr));
+            let desugared = desugar_expr(arena, loc_sub_expr, src, line_info, module_path);
+
+            arena.alloc(Loc::at(
+                loc_expr.region,
+                TrySuffixed(arena.alloc(desugared.value)),
+            ))
+        }
+    }
+}
+
+/// Rewrite the first def in this block whose body is `expr?` into a `when` on the Result,
+/// with the defs that depend on it in the `Ok` branch and the `Err` returned as-is:
+///
+/// a = foo
+/// b = bar?
+/// c = 1
+/// baz a b c
+///
+/// becomes
+///
+/// a = foo
+/// c = 1
+/// when bar is
+///     Ok #ok ->
+///         b = #ok
+///         baz a b c
+///     Err #err -> Err #err
+///
+/// Defs in a block can be written in any order, so a def depends on the `?` def if it mentions
+/// a name bound by the `?` def or by another def that depends on it, wherever it is in the block.
+/// Later `?` defs and later `dbg` or `expect` statements stay in the `Ok` branch too, so they
+/// still run in the order they were written.
+///
+/// Returns None if there is no such def. The result is not desugared yet.
+fn desugar_try_suffixed_def<'a>(
+    arena: &'a Bump,
+    region: Region,
+    defs: &Defs<'a>,
+    loc_ret: &'a Loc<Expr<'a>>,
+) -> Option<&'a Loc<Expr<'a>>> {
+    let (try_index, loc_try_expr) = defs.tags.iter().enumerate().find_map(|(index, tag)| {
+        let loc_body = match &defs.value_defs[tag.split().err()?.index()] {
+            ValueDef::Body(_, body) => *body,
+            ValueDef::AnnotatedBody { body_expr, .. } => *body_expr,
+            _ => return None,
+        };
+
+        let expr = try_suffixed_expr(&loc_body.value)?;
+
+        Some((index, Loc::at(loc_body.region, *expr)))
+    })?;
+
+    // The Ok value gets a name that is unique within the module, so that nested
+    // blocks with their own `?` defs do not shadow it.
+    let ok_name: &'a str = arena.alloc_str(&format!("#ok{}", loc_try_expr.region.start().offset));
+    let err_name = "#err";
+
+    let in_ok_branch = defs_in_ok_branch(defs, try_index);
+
+    let mut before = Defs::default();
+    let mut after = Defs::default();
+
+    for (index, tag) in defs.tags.iter().enumerate() {
+        let target = if in_ok_branch[index] {
+            &mut after
+        } else {
+            &mut before
+        };
+        let def_region = defs.regions[index];
+        let spaces_before = &defs.spaces[defs.space_before[index].indices()];
+        let spaces_after = &defs.spaces[defs.space_after[index].indices()];
+
+        match tag.split() {
+            Ok(type_index) => target.push_type_def(
+                defs.type_defs[type_index.index()],
+                def_region,
+                spaces_before,
+                spaces_after,
+            ),
+            Err(value_index) => {
+                let mut value_def = defs.value_defs[value_index.index()];
+
+                if index == try_index {
+                    let ok_var = arena.alloc(Loc::at(
+                        loc_try_expr.region,
+                        Var {
+                            module_name: "",
+                            ident: ok_name,
+                        },
+                    ));
+
+                    match &mut value_def {
+                        ValueDef::Body(_, body) => *body = ok_var,
+                        ValueDef::AnnotatedBody { body_expr, .. } => *body_expr = ok_var,
+                        _ => unreachable!(),
+                    }
+                }
+
+                target.push_value_def(value_def, def_region, spaces_before, spaces_after);
+            }
+        }
+    }
+
+    let try_region = Region::span_across(&defs.regions[try_index], &loc_ret.region);
+    let ok_branch_expr = Loc::at(try_region, Defs(arena.alloc(after), loc_ret));
+
+    let ok_branch = arena.alloc(WhenBranch {
+        patterns: arena.alloc([tag_pattern(arena, "Ok", ok_name, loc_try_expr.region)]),
+        value: ok_branch_expr,
+        guard: None,
+    });
+
+    let err_var = arena.alloc(Loc::at(
+        loc_try_expr.region,
+        Var {
+            module_name: "",
+            ident: err_name,
+        },
+    ));
+    let err_branch = arena.alloc(WhenBranch {
+        patterns: arena.alloc([tag_pattern(arena, "Err", err_name, loc_try_expr.region)]),
+        value: Loc::at(
+            loc_try_expr.region,
+            Apply(
+                arena.alloc(Loc::at(loc_try_expr.region, Tag("Err"))),
+                arena.alloc([&*err_var]),
+                CalledVia::Space,
+            ),
+        ),
+        guard: None,
+    });
+
+    let when_expr = arena.alloc(Loc::at(
+        try_region,
+        When(
+            arena.alloc(loc_try_expr),
+            arena.alloc([&*ok_branch, &*err_branch]),
+        ),
+    ));
+
+    if before.is_empty() {
+        Some(when_expr)
+    } else {
+        Some(arena.alloc(Loc::at(region, Defs(arena.alloc(before), when_expr))))
+    }
+}
+
+/// `expr` if the body of a def is `expr?`
+fn try_suffixed_expr<'a>(body: &Expr<'a>) -> Option<&'a Expr<'a>> {
+    match body {
+        SpaceBefore(expr, _) | SpaceAfter(expr, _) => try_suffixed_expr(expr),
+        TrySuffixed(expr) => Some(expr),
+        _ => None,
+    }
+}
+
+/// For each def in the block, whether it has to go in the `Ok` branch of the `?` def at
+/// `try_index`. See [desugar_try_suffixed_def].
+fn defs_in_ok_branch(defs: &Defs<'_>, try_index: usize) -> std::vec::Vec<bool> {
+    let mut in_ok_branch: std::vec::Vec<bool> = defs
+        .tags
+        .iter()
+        .enumerate()
+        .map(|(index, tag)| match tag.split() {
+            Ok(_) => false,
+            Err(value_index) => match &defs.value_defs[value_index.index()] {
+                _ if index == try_index => true,
+                _ if index < try_index => false,
+                ValueDef::Body(_, body)
+                | ValueDef::AnnotatedBody {
+                    body_expr: body, ..
+                } => try_suffixed_expr(&body.value).is_some(),
+                ValueDef::Annotation(..) => false,
+                ValueDef::Dbg { .. } | ValueDef::Expect { .. } | ValueDef::ExpectFx { .. } => true,
+            },
+        })
+        .collect();
+
+    // Keep adding the defs that mention a name bound in the `Ok` branch, until there are none left
+    loop {
+        let mut ok_branch_names = std::vec::Vec::new();
+
+        for (index, tag) in defs.tags.iter().enumerate() {
+            if let (true, Err(value_index)) = (in_ok_branch[index], tag.split()) {
+                value_def_idents(&defs.value_defs[value_index.index()], &mut ok_branch_names);
+            }
+        }
+
+        let mut changed = false;
+
+        for (index, tag) in defs.tags.iter().enumerate() {
+            if let (false, Err(value_index)) = (in_ok_branch[index], tag.split()) {
+                if value_def_mentions(&defs.value_defs[value_index.index()], &ok_branch_names) {
+                    in_ok_branch[index] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            return in_ok_branch;
+        }
+    }
+}
+
+/// The names a value def binds, e.g. `a` and `b` for `{ a, b } = foo`
+fn value_def_idents<'a>(value_def: &ValueDef<'a>, idents: &mut std::vec::Vec<&'a str>) {
+    match value_def {
+        ValueDef::Annotation(loc_pattern, _) => pattern_idents(&loc_pattern.value, idents),
+        ValueDef::Body(loc_pattern, _)
+        | ValueDef::AnnotatedBody {
+            body_pattern: loc_pattern,
+            ..
+        } => pattern_idents(&loc_pattern.value, idents),
+        ValueDef::Dbg { .. } | ValueDef::Expect { .. } | ValueDef::ExpectFx { .. } => {}
+    }
+}
+
+fn pattern_idents<'a>(pattern: &Pattern<'a>, idents: &mut std::vec::Vec<&'a str>) {
+    use Pattern::*;
+
+    match pattern {
+        Identifier(ident) | OptionalField(ident, _) => idents.push(ident),
+        Apply(_, loc_args) => {
+            for loc_arg in loc_args.iter() {
+                pattern_idents(&loc_arg.value, idents);
+            }
+        }
+        RecordDestructure(loc_patterns) | Tuple(loc_patterns) | List(loc_patterns) => {
+            for loc_pattern in loc_patterns.iter() {
+                pattern_idents(&loc_pattern.value, idents);
+            }
+        }
+        RequiredField(_, loc_pattern) => pattern_idents(&loc_pattern.value, idents),
+        ListRest(Some((_, pattern_as))) => idents.push(pattern_as.identifier.value),
+        As(loc_pattern, pattern_as) => {
+            pattern_idents(&loc_pattern.value, idents);
+            idents.push(pattern_as.identifier.value);
+        }
+        SpaceBefore(pattern, _) | SpaceAfter(pattern, _) => pattern_idents(pattern, idents),
+        Tag(_)
+        | OpaqueRef(_)
+        | NumLiteral(_)
+        | NonBase10Literal { .. }
+        | FloatLiteral(_)
+        | StrLiteral(_)
+        | Underscore(_)
+        | SingleQuote(_)
+        | ListRest(None)
+        | Malformed(_)
+        | MalformedIdent(_, _)
+        | QualifiedIdentifier { .. } => {}
+    }
+}
+
+/// Whether a value def mentions any of `names`. An annotation mentions the names it annotates,
+/// so it stays with the body it belongs to.
+fn value_def_mentions(value_def: &ValueDef<'_>, names: &[&str]) -> bool {
+    match value_def {
+        ValueDef::Annotation(loc_pattern, _) => {
+            let mut idents = std::vec::Vec::new();
+            pattern_idents(&loc_pattern.value, &mut idents);
+
+            idents.iter().any(|ident| names.contains(ident))
+        }
+        ValueDef::Body(loc_pattern, loc_body)
+        | ValueDef::AnnotatedBody {
+            body_pattern: loc_pattern,
+            body_expr: loc_body,
+            ..
+        } => pattern_mentions(&loc_pattern.value, names) || expr_mentions(&loc_body.value, names),
+        ValueDef::Dbg { condition, .. }
+        | ValueDef::Expect { condition, .. }
+        | ValueDef::ExpectFx { condition, .. } => expr_mentions(&condition.value, names),
+    }
+}
+
+/// Whether an expression mentions any of `names` as an unqualified variable. This ignores
+/// shadowing, so it may find a mention where the name actually refers to something else.
+fn expr_mentions(expr: &Expr<'_>, names: &[&str]) -> bool {
+    match expr {
+        Var {
+            module_name: "",
+            ident,
+        } => names.contains(ident),
+        Var { .. }
+        | Float(_)
+        | Num(_)
+        | NonBase10Int { .. }
+        | SingleQuote(_)
+        | AccessorFunction(_)
+        | IngestedFile(_, _)
+        | Underscore(_)
+        | Crash
+        | Tag(_)
+        | OpaqueRef(_)
+        | MalformedIdent(_, _)
+        | MalformedClosure => false,
+        Str(str_literal) => str_mentions(str_literal, names),
+        RecordAccess(sub_expr, _)
+        | TupleAccess(sub_expr, _)
+        | Suffixed(sub_expr)
+        | TrySuffixed(sub_expr)
+        | SpaceBefore(sub_expr, _)
+        | SpaceAfter(sub_expr, _)
+        | ParensAround(sub_expr) => expr_mentions(sub_expr, names),
+        List(items) | Tuple(items) => items.iter().any(|item| expr_mentions(&item.value, names)),
+        RecordUpdate { update, fields } => {
+            expr_mentions(&update.value, names)
+                || fields
+                    .iter()
+                    .any(|field| field_mentions(&field.value, names))
+        }
+        Record(fields) => fields
+            .iter()
+            .any(|field| field_mentions(&field.value, names)),
+        RecordBuilder(fields) => fields
+            .iter()
+            .any(|field| builder_field_mentions(&field.value, names)),
+        Closure(loc_args, loc_body) => {
+            loc_args
+                .iter()
+                .any(|loc_arg| pattern_mentions(&loc_arg.value, names))
+                || expr_mentions(&loc_body.value, names)
+        }
+        Defs(defs, loc_ret) => {
+            defs.value_defs
+                .iter()
+                .any(|value_def| value_def_mentions(value_def, names))
+                || expr_mentions(&loc_ret.value, names)
+        }
+        Backpassing(loc_patterns, loc_body, loc_ret) => {
+            loc_patterns
+                .iter()
+                .any(|loc_pattern| pattern_mentions(&loc_pattern.value, names))
+                || expr_mentions(&loc_body.value, names)
+                || expr_mentions(&loc_ret.value, names)
+        }
+        Expect(condition, continuation)
+        | Dbg(condition, continuation)
+        | LowLevelDbg(_, condition, continuation) => {
+            expr_mentions(&condition.value, names) || expr_mentions(&continuation.value, names)
+        }
+        Apply(loc_fn, loc_args, _) => {
+            expr_mentions(&loc_fn.value, names)
+                || loc_args
+                    .iter()
+                    .any(|loc_arg| expr_mentions(&loc_arg.value, names))
+        }
+        BinOps(lefts, right) => {
+            lefts
+                .iter()
+                .any(|(loc_left, _)| expr_mentions(&loc_left.value, names))
+                || expr_mentions(&right.value, names)
+        }
+        UnaryOp(loc_arg, _) => expr_mentions(&loc_arg.value, names),
+        If(if_thens, final_else) => {
+            if_thens.iter().any(|(condition, then)| {
+                expr_mentions(&condition.value, names) || expr_mentions(&then.value, names)
+            }) || expr_mentions(&final_else.value, names)
+        }
+        When(loc_cond, branches) => {
+            expr_mentions(&loc_cond.value, names)
+                || branches.iter().any(|branch| {
+                    branch
+                        .patterns
+                        .iter()
+                        .any(|loc_pattern| pattern_mentions(&loc_pattern.value, names))
+                        || expr_mentions(&branch.value.value, names)
+                        || branch
+                            .guard
+                            .as_ref()
+                            .map_or(false, |guard| expr_mentions(&guard.value, names))
+                })
+        }
+        PrecedenceConflict(conflict) => expr_mentions(&conflict.expr.value, names),
+        MultipleRecordBuilders(sub_expr) | UnappliedRecordBuilder(sub_expr) => {
+            expr_mentions(&sub_expr.value, names)
+        }
+    }
+}
+
+fn str_mentions(str_literal: &StrLiteral<'_>, names: &[&str]) -> bool {
+    let segment_mentions = |segment: &StrSegment<'_>| match segment {
+        StrSegment::Interpolated(loc_expr) | StrSegment::DeprecatedInterpolated(loc_expr) => {
+            expr_mentions(loc_expr.value, names)
+        }
+        StrSegment::Plaintext(_) | StrSegment::Unicode(_) | StrSegment::EscapedChar(_) => false,
+    };
+
+    match str_literal {
+        StrLiteral::PlainLine(_) => false,
+        StrLiteral::Line(segments) => segments.iter().any(segment_mentions),
+        StrLiteral::Block(lines) => lines
+            .iter()
+            .any(|segments| segments.iter().any(segment_mentions)),
+    }
+}
+
+fn field_mentions(field: &AssignedField<'_, Expr<'_>>, names: &[&str]) -> bool {
+    match field {
+        AssignedField::RequiredValue(_, _, loc_value)
+        | AssignedField::OptionalValue(_, _, loc_value) => expr_mentions(&loc_value.value, names),
+        // `{ name }` is short for `{ name: name }`
+        AssignedField::LabelOnly(label) => names.contains(&label.value),
+        AssignedField::SpaceBefore(field, _) | AssignedField::SpaceAfter(field, _) => {
+            field_mentions(field, names)
+        }
+        AssignedField::Malformed(_) => false,
     }
 }
 
+fn builder_field_mentions(field: &RecordBuilderField<'_>, names: &[&str]) -> bool {
+    match field {
+        RecordBuilderField::Value(_, _, loc_value)
+        | RecordBuilderField::ApplyValue(_, _, _, loc_value) => {
+            expr_mentions(&loc_value.value, names)
+        }
+        RecordBuilderField::LabelOnly(label) => names.contains(&label.value),
+        RecordBuilderField::SpaceBefore(field, _) | RecordBuilderField::SpaceAfter(field, _) => {
+            builder_field_mentions(field, names)
+        }
+        RecordBuilderField::Malformed(_) => false,
+    }
+}
+
+/// Whether a pattern's default values, e.g. `0` in `{ x ? 0 }`, mention any of `names`
+fn pattern_mentions(pattern: &Pattern<'_>, names: &[&str]) -> bool {
+    use Pattern::*;
+
+    match pattern {
+        OptionalField(_, loc_default) => expr_mentions(&loc_default.value, names),
+        Apply(_, loc_args) => loc_args
+            .iter()
+            .any(|loc_arg| pattern_mentions(&loc_arg.value, names)),
+        RecordDestructure(loc_patterns) | Tuple(loc_patterns) | List(loc_patterns) => loc_patterns
+            .iter()
+            .any(|loc_pattern| pattern_mentions(&loc_pattern.value, names)),
+        RequiredField(_, loc_pattern) | As(loc_pattern, _) => {
+            pattern_mentions(&loc_pattern.value, names)
+        }
+        SpaceBefore(pattern, _) | SpaceAfter(pattern, _) => pattern_mentions(pattern, names),
+        Identifier(_)
+        | Tag(_)
+        | OpaqueRef(_)
+        | NumLiteral(_)
+        | NonBase10Literal { .. }
+        | FloatLiteral(_)
+        | StrLiteral(_)
+        | Underscore(_)
+        | SingleQuote(_)
+        | ListRest(_)
+        | Malformed(_)
+        | MalformedIdent(_, _)
+        | QualifiedIdentifier { .. } => false,
+    }
+}
+
+/// A pattern like `Ok #ok`
+fn tag_pattern<'a>(
+    arena: &'a Bump,
+    tag_name: &'a str,
+    ident: &'a str,
+    region: Region,
+) -> Loc<Pattern<'a>> {
+    let loc_tag = arena.alloc(Loc::at(region, Pattern::Tag(tag_name)));
+    let loc_ident = Loc::at(region, Pattern::Identifier(ident));
+
+    Loc::at(region, Pattern::Apply(loc_tag, arena.alloc([loc_ident])))
+}
+
 fn desugar_str_segments<'a>(
     arena: &'a Bump,
     segments: &'a [StrSegment<'a>],
@@ -107,7 +107,7 @@ impl<'a> Formattable for Expr<'a> {
             Tuple(fields) => is_collection_multiline(fields),
             RecordUpdate { fields, .. } => is_collection_multiline(fields),
             RecordBuilder(fields) => is_collection_multiline(fields),
-            Suffixed(subexpr) => subexpr.is_multiline(),
+            Suffixed(subexpr) | TrySuffixed(subexpr) => subexpr.is_multiline(),
         }
     }
 
@@ -517,6 +517,10 @@ impl<'a> Formattable for Expr<'a> {
                 sub_expr.format_with_options(buf, parens, newlines, indent);
                 buf.push('!');
             }
+            TrySuffixed(sub_expr) => {
+                sub_expr.format_with_options(buf, parens, newlines, indent);
+                buf.push('?');
+            }
         }
     }
 }
@@ -762,6 +762,7 @@ impl<'a> RemoveSpaces<'a> for Expr<'a> {
             Expr::SpaceAfter(a, _) => a.remove_spaces(arena),
             Expr::SingleQuote(a) => Expr::Num(a),
             Expr::Suffixed(a) => a.remove_spaces(arena),
+            Expr::TrySuffixed(a) => Expr::TrySuffixed(arena.alloc(a.remove_spaces(arena))),
         }
     }
 }
@@ -23,6 +23,7 @@ pub use roc_load_internal::file::{
 };
 pub use roc_load_internal::module::{
     CheckedModule, EntryPoint, Expectations, ExposedToHost, LoadedModule, MonomorphizedModule,
+    UnresolvedHostType,
 };
 pub use roc_solve::FunctionKind;
 
@@ -185,7 +185,7 @@ mod test_reporting {
         let mut buf = String::new();
 
         match infer_expr_help_new(subdir, arena, src) {
-            Err(LoadingProblem::FormattedReport(fail)) => fail,
+            Err(LoadingProblem::FormattedReport(fail, _)) => fail,
             Ok((module_src, type_problems, can_problems, home, interns)) => {
                 let lines = LineInfo::new(&module_src);
                 let src_lines: Vec<&str> = module_src.split('\n').collect();
@@ -600,7 +600,7 @@ mod test_reporting {
     should be grouped.
 
     6│          if selectedId != thisId == adminsId then
-                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                              ^^        ^^
     "
     );
 
@@ -4587,6 +4587,29 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        expr_in_parens_end,
+        indoc!(
+            r"
+            x = (1, 2
+            "
+        ),
+        @r"
+    ── UNFINISHED PARENTHESES in tmp/expr_in_parens_end/Test.roc ───────────────────
+
+    I am partway through parsing a parenthesized expression or tuple, but
+    I got stuck here:
+
+    4│      x = (1, 2
+    5│
+    6│
+        ^
+
+    I was expecting to see a closing parenthesis to match the opening one,
+    so try adding a ) and see if that helps?
+    "
+    );
+
     // TODO bad error message
     test_report!(
         type_in_parens_start,
@@ -10519,6 +10542,28 @@ In roc, functions are always written as a lambda, like{}
     "#
     );
 
+    test_report!(
+        misplaced_try_suffix,
+        indoc!(
+            r#"
+            Str.concat (Ok "a"?) "b"
+            "#
+        ),
+        @r#"
+    ── MISPLACED TRY in /code/proj/Main.roc ────────────────────────────────────────
+
+    This ? is not the whole body of a definition:
+
+    4│      Str.concat (Ok "a"?) "b"
+                        ^^^^^^^
+
+    A ? can only be used on the right side of a definition inside a block,
+    so the rest of the block can be skipped when the result is an `Err`.
+
+    Tip: Give the result a name, like x = foo?, and use that name instead.
+    "#
+    );
+
     test_report!(
         record_builder_apply_non_function,
         indoc!(
@@ -11767,7 +11812,7 @@ In roc, functions are always written as a lambda, like{}
     This 2nd argument to `contains` has an unexpected type:
 
     7│      List.contains digits '☃'
-                                 ^^^^^
+                                 ^^^
 
     The argument is a Unicode scalar value of type:
 
@@ -13993,4 +14038,58 @@ In roc, functions are always written as a lambda, like{}
     make partial application explicit.
     "
     );
+
+    test_report!(
+        underline_after_multibyte_chars,
+        indoc!(
+            r#"
+            Str.concat "héllo wörld" 1
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This 2nd argument to `concat` has an unexpected type:
+
+    4│      Str.concat "héllo wörld" 1
+                                     ^
+
+    The argument is a number of type:
+
+        Num *
+
+    But `concat` needs its 2nd argument to be:
+
+        Str
+    "#
+    );
+
+    test_report!(
+        int_literal_outside_annotated_range,
+        indoc!(
+            r"
+            x : U8
+            x = 300
+
+            x
+            "
+        ),
+        @r"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    Something is off with the body of the `x` definition:
+
+    4│      x : U8
+    5│      x = 300
+                ^^^
+
+    The body is a number of type:
+
+        I16, U16, F32, I32, U32, F64, I64, U64, I128, Dec, or U128
+
+    But the type annotation on `x` says it should be:
+
+        U8
+    "
+    );
 }
@@ -60,7 +60,9 @@ use roc_problem::Severity;
 use roc_region::all::{LineInfo, Loc, Region};
 #[cfg(not(target_family = "wasm"))]
 use roc_reporting::report::to_https_problem_report_string;
-use roc_reporting::report::{to_file_problem_report_string, Palette, RenderTarget};
+use roc_reporting::report::{
+    to_file_problem_report_string_with_summary, Palette, RenderTarget, ReportSummary,
+};
 use roc_solve::module::{extract_module_owned_implementations, SolveConfig, Solved, SolvedModule};
 use roc_solve::FunctionKind;
 use roc_solve_problem::TypeError;
@@ -950,8 +952,8 @@ pub enum LoadingProblem<'a> {
     ErrJoiningWorkerThreads,
     TriedToImportAppModule,
 
-    /// a formatted report
-    FormattedReport(String),
+    /// a formatted report, along with a summary of it for tools when there is one
+    FormattedReport(String, Option<ReportSummary>),
 
     ImportCycle(PathBuf, Vec<ModuleId>),
     IncorrectModuleName(FileError<'a, IncorrectModuleName<'a>>),
@@ -1126,11 +1128,12 @@ impl<'a> LoadStart<'a> {
                         .into_inner()
                         .into_module_ids();
 
-                    let report = report_loading_problem(problem, module_ids, render, palette);
+                    let (report, summary) =
+                        report_loading_problem_with_summary(problem, module_ids, render, palette);
 
                     // TODO try to gracefully recover and continue
                     // instead of changing the control flow to exit.
-                    return Err(LoadingProblem::FormattedReport(report));
+                    return Err(LoadingProblem::FormattedReport(report, summary));
                 }
             }
         };
@@ -1511,27 +1514,28 @@ fn state_thread_step<'a>(
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
-                    let buf = to_file_problem_report_string(filename, error);
-                    Err(LoadingProblem::FormattedReport(buf))
+                    let (buf, summary) =
+                        to_file_problem_report_string_with_summary(filename, error);
+                    Err(LoadingProblem::FormattedReport(buf, Some(summary)))
                 }
 
                 Msg::FailedToParse(problem) => {
                     let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
-                    let buf = to_parse_problem_report(
+                    let (buf, summary) = to_parse_problem_report(
                         problem,
                         module_ids,
                         state.constrained_ident_ids,
                         state.render,
                         state.palette,
                     );
-                    Err(LoadingProblem::FormattedReport(buf))
+                    Err(LoadingProblem::FormattedReport(buf, Some(summary)))
                 }
                 Msg::IncorrectModuleName(FileError {
                     problem: SourceError { problem, bytes },
                     filename,
                 }) => {
                     let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
-                    let buf = to_incorrect_module_name_report(
+                    let (buf, summary) = to_incorrect_module_name_report(
                         module_ids,
                         state.constrained_ident_ids,
                         problem,
@@ -1539,7 +1543,7 @@ fn state_thread_step<'a>(
                         bytes,
                         state.render,
                     );
-                    Err(LoadingProblem::FormattedReport(buf))
+                    Err(LoadingProblem::FormattedReport(buf, Some(summary)))
                 }
                 msg => {
                     // This is where most of the main thread's work gets done.
@@ -1574,27 +1578,27 @@ fn state_thread_step<'a>(
 
                             // if parsing failed, this module did not add anything to IdentIds
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_parse_problem_report(
+                            let (buf, summary) = to_parse_problem_report(
                                 problem,
                                 module_ids,
                                 root_exposed_ident_ids,
                                 render,
                                 palette,
                             );
-                            Err(LoadingProblem::FormattedReport(buf))
+                            Err(LoadingProblem::FormattedReport(buf, Some(summary)))
                         }
                         Err(LoadingProblem::ImportCycle(filename, cycle)) => {
                             let module_ids = arc_modules.lock().clone().into_module_ids();
 
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_import_cycle_report(
+                            let (buf, summary) = to_import_cycle_report(
                                 module_ids,
                                 root_exposed_ident_ids,
                                 cycle,
                                 filename,
                                 render,
                             );
-                            return Err(LoadingProblem::FormattedReport(buf));
+                            return Err(LoadingProblem::FormattedReport(buf, Some(summary)));
                         }
                         Err(LoadingProblem::IncorrectModuleName(FileError {
                             problem: SourceError { problem, bytes },
@@ -1603,7 +1607,7 @@ fn state_thread_step<'a>(
                             let module_ids = arc_modules.lock().clone().into_module_ids();
 
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_incorrect_module_name_report(
+                            let (buf, summary) = to_incorrect_module_name_report(
                                 module_ids,
                                 root_exposed_ident_ids,
                                 problem,
@@ -1611,7 +1615,7 @@ fn state_thread_step<'a>(
                                 bytes,
                                 render,
                             );
-                            return Err(LoadingProblem::FormattedReport(buf));
+                            return Err(LoadingProblem::FormattedReport(buf, Some(summary)));
                         }
                         Err(e) => Err(e),
                     }
@@ -1633,17 +1637,38 @@ pub fn report_loading_problem(
     render: RenderTarget,
     palette: Palette,
 ) -> String {
+    report_loading_problem_with_summary(problem, module_ids, render, palette).0
+}
+
+/// Like [report_loading_problem], but also returns a summary of the report for tools.
+pub fn report_loading_problem_with_summary(
+    problem: LoadingProblem<'_>,
+    module_ids: ModuleIds,
+    render: RenderTarget,
+    palette: Palette,
+) -> (String, Option<ReportSummary>) {
     match problem {
         LoadingProblem::ParsingFailed(problem) => {
             // if parsing failed, this module did not add anything to IdentIds
             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
 
-            to_parse_problem_report(problem, module_ids, root_exposed_ident_ids, render, palette)
+            let (report, summary) = to_parse_problem_report(
+                problem,
+                module_ids,
+                root_exposed_ident_ids,
+                render,
+                palette,
+            );
+
+            (report, Some(summary))
         }
         LoadingProblem::ImportCycle(filename, cycle) => {
             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
 
-            to_import_cycle_report(module_ids, root_exposed_ident_ids, cycle, filename, render)
+            let (report, summary) =
+                to_import_cycle_report(module_ids, root_exposed_ident_ids, cycle, filename, render);
+
+            (report, Some(summary))
         }
         LoadingProblem::IncorrectModuleName(FileError {
             problem: SourceError { problem, bytes },
@@ -1651,18 +1676,22 @@ pub fn report_loading_problem(
         }) => {
             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
 
-            to_incorrect_module_name_report(
+            let (report, summary) = to_incorrect_module_name_report(
                 module_ids,
                 root_exposed_ident_ids,
                 problem,
                 filename,
                 bytes,
                 render,
-            )
+            );
+
+            (report, Some(summary))
         }
-        LoadingProblem::FormattedReport(report) => report,
+        LoadingProblem::FormattedReport(report, summary) => (report, summary),
         LoadingProblem::FileProblem { filename, error } => {
-            to_file_problem_report_string(filename, error)
+            let (report, summary) = to_file_problem_report_string_with_summary(filename, error);
+
+            (report, Some(summary))
         }
         err => todo!("Loading error: {:?}", err),
     }
@@ -1909,6 +1938,7 @@ fn load_multi_threaded<'a>(
                     "command can sometimes give a more helpful error report than other commands.\n\n"
                 )
                 .to_string(),
+                None,
             ))
         })
     }
@@ -2198,7 +2228,7 @@ fn check_for_missing_package_shorthand_in_cache<'a>(
                             },
                             _=>
                                 format!("The package shorthand '{shorthand}' that you are using in the 'imports' section of the header of module '{module_path}' doesn't exist.\nCheck that package shorthand is correct or reference the package in an 'app' or 'package' header.")
-                        }))
+                        }, None))
                     } else {
                         None
                     }
@@ -2282,7 +2312,7 @@ fn update<'a>(
                                         Problem::InvalidUrl(url_err),
                                         header.module_path,
                                     );
-                                    return Err(LoadingProblem::FormattedReport(buf));
+                                    return Err(LoadingProblem::FormattedReport(buf, None));
                                 }
                             }
                         }
@@ -3214,8 +3244,9 @@ fn finish_specialization<'a>(
                     }
                     Valid(To::NewPackage(p_or_p)) => PathBuf::from(p_or_p.as_str()),
                     other => {
-                        let buf = report_cannot_run(state.root_id, state.root_path, other);
-                        return Err(LoadingProblem::FormattedReport(buf));
+                        let (buf, summary) =
+                            report_cannot_run(state.root_id, state.root_path, other);
+                        return Err(LoadingProblem::FormattedReport(buf, Some(summary)));
                     }
                 };
 
@@ -4203,7 +4234,9 @@ fn load_packages<'a>(
                     Err(problem) => {
                         let buf = to_https_problem_report_string(src, problem, filename);
 
-                        load_messages.push(Msg::FailedToLoad(LoadingProblem::FormattedReport(buf)));
+                        load_messages.push(Msg::FailedToLoad(LoadingProblem::FormattedReport(
+                            buf, None,
+                        )));
                         return;
                     }
                 }
@@ -4390,8 +4423,7 @@ fn build_header<'a>(
 
     // Make sure the module_ids has ModuleIds for all our deps,
     // then record those ModuleIds in can_module_ids for later.
-    let mut scope: MutMap<Ident, (Symbol, Region)> =
-        HashMap::with_capacity_and_hasher(scope_size, default_hasher());
+    let mut scope: Vec<(Ident, (Symbol, Region))> = Vec::with_capacity(scope_size);
     let home: ModuleId;
     let name: PQModuleName;
     let symbols_from_requires;
@@ -4434,9 +4466,8 @@ fn build_header<'a>(
                 let symbol = Symbol::new(module_id, ident_id);
 
                 // Since this value is exposed, add it to our module's default scope.
-                debug_assert!(!scope.contains_key(&loc_ident.value));
-
-                scope.insert(loc_ident.value, (symbol, loc_ident.region));
+                // Names imported more than once are reported during canonicalization.
+                scope.push((loc_ident.value, (symbol, loc_ident.region)));
             }
         }
 
@@ -4468,9 +4499,7 @@ fn build_header<'a>(
                 let symbol = Symbol::new(module_id, ident_id);
 
                 // Since this value is exposed, add it to our module's default scope.
-                debug_assert!(!scope.contains_key(&ident.clone()));
-
-                scope.insert(ident, (symbol, entry.ident.region));
+                scope.push((ident, (symbol, entry.ident.region)));
                 symbols_from_requires.push((Loc::at(entry.ident.region, symbol), entry.ann));
             }
 
@@ -4481,8 +4510,7 @@ fn build_header<'a>(
                 let symbol = Symbol::new(module_id, ident_id);
 
                 // Since this value is exposed, add it to our module's default scope.
-                debug_assert!(!scope.contains_key(&ident));
-                scope.insert(ident, (symbol, entry.region));
+                scope.push((ident, (symbol, entry.region)));
             }
 
             symbols_from_requires
@@ -6501,7 +6529,7 @@ fn to_import_cycle_report(
     import_cycle: Vec<ModuleId>,
     filename: PathBuf,
     render: RenderTarget,
-) -> String {
+) -> (String, ReportSummary) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6547,11 +6575,12 @@ fn to_import_cycle_report(
         title: "IMPORT CYCLE".to_string(),
         severity: Severity::RuntimeError,
     };
+    let summary = report.summary("import-cycle", None);
 
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, summary)
 }
 
 fn to_incorrect_module_name_report<'a>(
@@ -6561,7 +6590,7 @@ fn to_incorrect_module_name_report<'a>(
     filename: PathBuf,
     src: &'a [u8],
     render: RenderTarget,
-) -> String {
+) -> (String, ReportSummary) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6596,11 +6625,15 @@ fn to_incorrect_module_name_report<'a>(
         title: "INCORRECT MODULE NAME".to_string(),
         severity: Severity::RuntimeError,
     };
+    let summary = report.summary(
+        "incorrect-module-name",
+        Some(lines.convert_region(found.region)),
+    );
 
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, summary)
 }
 
 fn to_parse_problem_report<'a>(
@@ -6609,7 +6642,7 @@ fn to_parse_problem_report<'a>(
     all_ident_ids: IdentIdsByModule,
     render: RenderTarget,
     palette: Palette,
-) -> String {
+) -> (String, ReportSummary) {
     use roc_reporting::report::{parse_problem, RocDocAllocator};
 
     // TODO this is not in fact safe
@@ -6631,6 +6664,11 @@ fn to_parse_problem_report<'a>(
     let starting_line = 0;
 
     let lines = LineInfo::new(src);
+    let region = problem
+        .problem
+        .problem
+        .get_region()
+        .map(|region| lines.convert_region(region));
 
     let report = parse_problem(
         &alloc,
@@ -6639,19 +6677,20 @@ fn to_parse_problem_report<'a>(
         starting_line,
         problem,
     );
+    let summary = report.summary("syntax-error", region);
 
     let mut buf = String::new();
 
     report.render(render, &mut buf, &alloc, &palette);
 
-    buf
+    (buf, summary)
 }
 
 fn report_cannot_run(
     module_id: ModuleId,
     filename: PathBuf,
     platform_path: &PlatformPath,
-) -> String {
+) -> (String, ReportSummary) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
     use PlatformPath::*;
@@ -6728,9 +6767,11 @@ fn report_cannot_run(
         }
     };
 
+    let summary = report.summary("no-platform", None);
+
     let palette = DEFAULT_PALETTE;
     let mut buf = String::new();
     report.render_color_terminal(&mut buf, &alloc, &palette);
 
-    buf
+    (buf, summary)
 }
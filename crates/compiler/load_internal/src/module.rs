@@ -6,8 +6,8 @@ use roc_can::{
     expr::{Declarations, PendingDerives},
     module::{Module, ResolvedImplementations},
 };
-use roc_collections::{MutMap, MutSet, VecMap};
-use roc_module::ident::Ident;
+use roc_collections::{MutMap, MutSet, SendMap, VecMap};
+use roc_module::ident::{Ident, Lowercase};
 use roc_module::symbol::{
     IdentIds, IdentIdsByModule, Interns, ModuleId, PQModuleName, PackageQualified, Symbol,
 };
@@ -19,7 +19,7 @@ use roc_region::all::{Loc, Region};
 use roc_solve::module::Solved;
 use roc_solve_problem::TypeError;
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
-use roc_types::types::{Alias, Types};
+use roc_types::types::{Alias, AliasKind, ErrorType, Polarity, Types};
 use std::path::PathBuf;
 
 #[cfg(target_family = "wasm")]
@@ -81,6 +81,142 @@ impl LoadedModule {
             .map(|symbol| symbol.as_str(&self.interns))
             .collect()
     }
+
+    /// The type of each value exposed to the host, fully applied and with structural
+    /// aliases expanded. Opaque types keep their name, wrapping their expanded type.
+    ///
+    /// The host has to know exactly what these types are, so a type variable left
+    /// anywhere a value is stored is an error. Number types that were never pinned
+    /// down are fine, because they default to `I64` or `F64`.
+    pub fn host_exposed_types(&self) -> Result<Vec<(Symbol, ErrorType)>, UnresolvedHostType> {
+        // turning variables into types names them, which writes to the subs
+        let mut subs = self.solved.inner().clone();
+        let mut exposed: Vec<_> = self.exposed_to_host.iter().collect();
+
+        exposed.sort_by_key(|(symbol, _)| **symbol);
+
+        exposed
+            .into_iter()
+            .map(|(symbol, var)| {
+                let typ = subs.var_to_error_type(*var, Polarity::Pos);
+
+                match find_type_variable(&typ, &mut Vec::new()) {
+                    Some(variable) => Err(UnresolvedHostType {
+                        symbol: *symbol,
+                        variable: variable.clone(),
+                    }),
+                    None => Ok((*symbol, expand_aliases(typ))),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A value exposed to the host whose type still contains a type variable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedHostType {
+    pub symbol: Symbol,
+    pub variable: Lowercase,
+}
+
+fn find_type_variable<'t>(
+    typ: &'t ErrorType,
+    recursion_vars: &mut Vec<&'t Lowercase>,
+) -> Option<&'t Lowercase> {
+    use ErrorType::*;
+
+    match typ {
+        FlexVar(name) | RigidVar(name) | FlexAbleVar(name, _) | RigidAbleVar(name, _) => {
+            if recursion_vars.contains(&name) {
+                None
+            } else {
+                Some(name)
+            }
+        }
+        Type(symbol, _) | Alias(symbol, ..) if symbol.module_id() == ModuleId::NUM => None,
+        Type(_, args) => find_first_type_variable(args, recursion_vars),
+        // an alias's arguments only matter through the type it stands for
+        Alias(_, _, real, _) => find_type_variable(real, recursion_vars),
+        // lambda sets are always resolved by the time a module is solved
+        Function(args, _, ret) => {
+            find_first_type_variable(args.iter().chain([ret.as_ref()]), recursion_vars)
+        }
+        // open extensions don't hold any values, so they can stay variables
+        Record(fields, _) => find_first_type_variable(
+            fields.values().map(|field| field.as_inner()),
+            recursion_vars,
+        ),
+        Tuple(elems, _) => {
+            find_first_type_variable(elems.iter().map(|(_, elem)| elem), recursion_vars)
+        }
+        TagUnion(tags, _, _) => find_first_type_variable(tags.values().flatten(), recursion_vars),
+        RecursiveTagUnion(rec_var, tags, _, _) => {
+            if let FlexVar(name) | RigidVar(name) = rec_var.as_ref() {
+                recursion_vars.push(name);
+            }
+
+            let found = find_first_type_variable(tags.values().flatten(), recursion_vars);
+
+            recursion_vars.pop();
+
+            found
+        }
+        Range(_) | Infinite | Error => None,
+    }
+}
+
+fn find_first_type_variable<'t>(
+    types: impl IntoIterator<Item = &'t ErrorType>,
+    recursion_vars: &mut Vec<&'t Lowercase>,
+) -> Option<&'t Lowercase> {
+    types
+        .into_iter()
+        .find_map(|typ| find_type_variable(typ, recursion_vars))
+}
+
+fn expand_aliases(typ: ErrorType) -> ErrorType {
+    use ErrorType::*;
+
+    let expand_all = |types: Vec<ErrorType>| types.into_iter().map(expand_aliases).collect();
+    let expand_tags = |tags: SendMap<_, Vec<ErrorType>>| {
+        tags.into_iter()
+            .map(|(tag_name, payload)| (tag_name, expand_all(payload)))
+            .collect()
+    };
+
+    match typ {
+        Alias(_, _, real, AliasKind::Structural) => expand_aliases(*real),
+        Alias(symbol, args, real, AliasKind::Opaque) => Alias(
+            symbol,
+            expand_all(args),
+            Box::new(expand_aliases(*real)),
+            AliasKind::Opaque,
+        ),
+        Type(symbol, args) => Type(symbol, expand_all(args)),
+        Function(args, closure, ret) => {
+            Function(expand_all(args), closure, Box::new(expand_aliases(*ret)))
+        }
+        Record(fields, ext) => Record(
+            fields
+                .into_iter()
+                .map(|(label, field)| (label, field.map_owned(expand_aliases)))
+                .collect(),
+            ext,
+        ),
+        Tuple(elems, ext) => Tuple(
+            elems
+                .into_iter()
+                .map(|(index, elem)| (index, expand_aliases(elem)))
+                .collect(),
+            ext,
+        ),
+        TagUnion(tags, ext, pol) => TagUnion(expand_tags(tags), ext, pol),
+        RecursiveTagUnion(rec_var, tags, ext, pol) => {
+            RecursiveTagUnion(rec_var, expand_tags(tags), ext, pol)
+        }
+        Range(types) => Range(expand_all(types)),
+        FlexVar(_) | RigidVar(_) | FlexAbleVar(..) | RigidAbleVar(..) | Infinite | Error => typ,
+    }
 }
 
 #[derive(Debug)]
@@ -94,7 +230,7 @@ pub(crate) struct ModuleHeader<'a> {
     pub(crate) imported_modules: MutMap<ModuleId, Region>,
     pub(crate) package_qualified_imported_modules: MutSet<PackageQualified<'a, ModuleId>>,
     pub(crate) exposes: Vec<Symbol>,
-    pub(crate) exposed_imports: MutMap<Ident, (Symbol, Region)>,
+    pub(crate) exposed_imports: Vec<(Ident, (Symbol, Region))>,
     pub(crate) parse_state: roc_parse::state::State<'a>,
     pub(crate) header_type: HeaderType<'a>,
     pub(crate) header_comments: &'a [CommentOrNewline<'a>],
@@ -197,7 +333,7 @@ pub struct ParsedModule<'a> {
     pub deps_by_name: MutMap<PQModuleName<'a>, ModuleId>,
     pub imported_modules: MutMap<ModuleId, Region>,
     pub exposed_ident_ids: IdentIds,
-    pub exposed_imports: MutMap<Ident, (Symbol, Region)>,
+    pub exposed_imports: Vec<(Ident, (Symbol, Region))>,
     pub parsed_defs: Defs<'a>,
     pub symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     pub header_type: HeaderType<'a>,
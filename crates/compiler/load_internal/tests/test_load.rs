@@ -22,7 +22,7 @@ use roc_load_internal::file::{
     ExecutionMode, LoadConfig, LoadResult, LoadStart, LoadingProblem, Threading,
 };
 use roc_load_internal::module::LoadedModule;
-use roc_module::ident::ModuleName;
+use roc_module::ident::{ModuleName, TagName};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_packaging::cache::RocCacheDir;
 use roc_problem::can::Problem;
@@ -34,6 +34,7 @@ use roc_solve::FunctionKind;
 use roc_target::TargetInfo;
 use roc_types::pretty_print::name_and_print_var;
 use roc_types::pretty_print::DebugPrint;
+use roc_types::types::ErrorType;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -111,7 +112,7 @@ fn multiple_modules(subdir: &str, files: Vec<(&str, &str)>) -> Result<LoadedModu
 
     match multiple_modules_help(subdir, arena, files) {
         Err(io_error) => panic!("IO trouble: {io_error:?}"),
-        Ok(Err(LoadingProblem::FormattedReport(buf))) => Err(buf),
+        Ok(Err(LoadingProblem::FormattedReport(buf, _))) => Err(buf),
         Ok(Err(loading_problem)) => Err(format!("{loading_problem:?}")),
         Ok(Ok(mut loaded_module)) => {
             let home = loaded_module.module_id;
@@ -209,7 +210,7 @@ fn load_fixture(
     );
     let mut loaded_module = match loaded {
         Ok(x) => x,
-        Err(roc_load_internal::file::LoadingProblem::FormattedReport(report)) => {
+        Err(roc_load_internal::file::LoadingProblem::FormattedReport(report, _)) => {
             println!("{report}");
             panic!("{}", report);
         }
@@ -357,6 +358,136 @@ fn import_transitive_alias() {
     assert!(multiple_modules("import_transitive_alias", modules).is_ok());
 }
 
+#[test]
+fn problems_as_json() {
+    let arena = Bump::new();
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                interface Main exposes [main] imports []
+
+                unused = 1
+
+                main = 2
+            "
+        ),
+    )];
+
+    let mut loaded_module = multiple_modules_help("problems_as_json", &arena, modules)
+        .unwrap()
+        .unwrap();
+
+    let diagnostics = roc_reporting::cli::problems_to_json(
+        &loaded_module.sources,
+        &loaded_module.interns,
+        &mut loaded_module.can_problems,
+        &mut loaded_module.type_problems,
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic["severity"], "warning");
+    assert_eq!(diagnostic["code"], "unused-def");
+    assert_eq!(diagnostic["title"], "UNUSED DEFINITION");
+    assert_eq!(
+        diagnostic["region"].to_string(),
+        r#"{"end":{"column":6,"line":2},"start":{"column":0,"line":2}}"#
+    );
+    assert_eq!(diagnostic["related"].to_string(), "[]");
+    assert!(diagnostic["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("problems_as_json/Main.roc"));
+    assert!(diagnostic["message"]
+        .as_str()
+        .unwrap()
+        .contains("`unused` is not used anywhere in your code."));
+}
+
+#[test]
+fn problems_as_json_related_regions() {
+    let arena = Bump::new();
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                interface Main exposes [main] imports []
+
+                main =
+                    x = 1
+                    x = 2
+                    x
+            "
+        ),
+    )];
+
+    let mut loaded_module =
+        multiple_modules_help("problems_as_json_related_regions", &arena, modules)
+            .unwrap()
+            .unwrap();
+
+    let diagnostics = roc_reporting::cli::problems_to_json(
+        &loaded_module.sources,
+        &loaded_module.interns,
+        &mut loaded_module.can_problems,
+        &mut loaded_module.type_problems,
+    );
+
+    let diagnostic = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic["code"] == "shadowing")
+        .unwrap();
+
+    assert_eq!(
+        diagnostic["region"].to_string(),
+        r#"{"end":{"column":5,"line":3},"start":{"column":4,"line":3}}"#
+    );
+    assert_eq!(
+        diagnostic["related"].to_string(),
+        r#"[{"end":{"column":5,"line":4},"start":{"column":4,"line":4}}]"#
+    );
+}
+
+#[test]
+fn loading_problem_as_json() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                interface Main exposes [main] imports []
+
+                main = if x then 1
+            "
+        ),
+    )];
+
+    let arena = Bump::new();
+    let (report, summary) =
+        match multiple_modules_help("loading_problem_as_json", &arena, modules) {
+            Ok(Err(LoadingProblem::FormattedReport(report, summary))) => (report, summary),
+            other => panic!("expected a formatted report, got {other:?}"),
+        };
+    let diagnostic = roc_reporting::cli::loading_problem_to_json(&report, summary.as_ref());
+
+    assert_eq!(diagnostic["severity"], "fatal");
+    assert_eq!(diagnostic["code"], "syntax-error");
+    assert_eq!(diagnostic["title"], "UNFINISHED IF");
+    assert!(diagnostic["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("loading_problem_as_json/Main.roc"));
+    assert_eq!(
+        diagnostic["region"].to_string(),
+        r#"{"end":{"column":0,"line":3},"start":{"column":18,"line":2}}"#
+    );
+    assert!(diagnostic["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("── UNFINISHED IF in "));
+}
+
 #[test]
 fn interface_with_deps() {
     let subs_by_module = Default::default();
@@ -859,6 +990,75 @@ fn platform_exposes_main_return_by_pointer_issue() {
     assert!(multiple_modules("platform_exposes_main_return_by_pointer_issue", modules).is_ok());
 }
 
+fn host_platform(provides: &str) -> String {
+    format!(
+        indoc!(
+            r#"
+            platform "test"
+                requires {{}} {{ main : Str }}
+                exposes []
+                packages {{}}
+                imports []
+                provides [mainForHost]
+
+            {}
+            "#
+        ),
+        provides
+    )
+}
+
+#[test]
+fn host_exposed_types_expand_aliases() {
+    let src = host_platform(indoc!(
+        r#"
+        Point : { x : Str, y : Str }
+
+        ConsList : [Cons Point ConsList, Nil]
+
+        mainForHost : ConsList
+        mainForHost = Cons { x: main, y: main } Nil
+        "#
+    ));
+    let modules = vec![("platform.roc", src.as_str())];
+
+    let loaded_module = multiple_modules("host_exposed_types_expand_aliases", modules).unwrap();
+    let host_exposed_types = loaded_module.host_exposed_types().unwrap();
+
+    // both aliases are replaced by the types they stand for
+    match host_exposed_types.as_slice() {
+        [(symbol, ErrorType::RecursiveTagUnion(_, tags, _, _))] => {
+            assert_eq!(symbol.as_str(&loaded_module.interns), "mainForHost");
+            assert!(matches!(
+                tags[&TagName("Cons".into())].as_slice(),
+                [ErrorType::Record(..), ErrorType::FlexVar(_)]
+            ));
+        }
+        other => panic!("unexpected host-exposed types: {other:?}"),
+    }
+}
+
+#[test]
+fn host_exposed_types_reject_type_variables() {
+    let src = host_platform(indoc!(
+        r#"
+        mainForHost : { name : Str, count : Num *, items : List a }
+        mainForHost = { name: main, count: 0, items: [] }
+        "#
+    ));
+    let modules = vec![("platform.roc", src.as_str())];
+
+    let loaded_module =
+        multiple_modules("host_exposed_types_reject_type_variables", modules).unwrap();
+    let unresolved = loaded_module.host_exposed_types().unwrap_err();
+
+    assert_eq!(
+        unresolved.symbol.as_str(&loaded_module.interns),
+        "mainForHost"
+    );
+    assert_eq!(unresolved.variable.as_str(), "a");
+}
+
 #[test]
 fn opaque_wrapped_unwrapped_outside_defining_module() {
     let modules = vec![
@@ -935,6 +1135,110 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
     );
 }
 
+#[test]
+fn same_name_imported_from_two_modules() {
+    let modules = vec![
+        (
+            "Left.roc",
+            indoc!(
+                r"
+                    interface Left exposes [value] imports []
+
+                    value = 1
+                    "
+            ),
+        ),
+        (
+            "Right.roc",
+            indoc!(
+                r"
+                    interface Right exposes [value] imports []
+
+                    value = 2
+                    "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r"
+                    interface Main exposes [main] imports [Left.{ value }, Right.{ value }]
+
+                    main = value
+                    "
+            ),
+        ),
+    ];
+
+    let err = multiple_modules("same_name_imported_from_two_modules", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r"
+                ── DUPLICATE NAME in tmp/same_name_imported_from_two_modules/Main.roc ──────────
+
+                The `value` name is first defined here:
+
+                1│  interface Main exposes [main] imports [Left.{ value }, Right.{ value }]
+                                                                  ^^^^^
+
+                But then it's defined a second time here:
+
+                1│  interface Main exposes [main] imports [Left.{ value }, Right.{ value }]
+                                                                                   ^^^^^
+
+                Since these variables have the same name, it's easy to use the wrong
+                one by accident. Give one of them a new name.
+
+                ── UNUSED IMPORT in tmp/same_name_imported_from_two_modules/Main.roc ───────────
+
+                Nothing from Right is used in this module.
+
+                1│  interface Main exposes [main] imports [Left.{ value }, Right.{ value }]
+                                                                           ^^^^^^^^^^^^^^^
+
+                Since Right isn't used, you don't need to import it.
+                "
+        ),
+        "\n{}",
+        err
+    );
+}
+
+#[test]
+fn exposed_but_not_defined_points_at_header() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                interface Main exposes [foo, bar] imports []
+
+                foo = 1
+                "
+        ),
+    )];
+
+    let err = multiple_modules("exposed_but_not_defined_points_at_header", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r"
+                ── MISSING DEFINITION in tmp/exposed_but_not_defined_points_at_header/Main.roc ─
+
+                `bar` is listed as exposed, but it isn't defined in this module.
+
+                1│  interface Main exposes [foo, bar] imports []
+                                                 ^^^
+
+                You can fix this by adding a definition for `bar`, or by removing it
+                from `exposes`.
+                "
+        ),
+        "\n{}",
+        err
+    );
+}
+
 #[test]
 fn issue_2863_module_type_does_not_exist() {
     let modules = vec![
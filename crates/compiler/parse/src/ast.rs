@@ -270,6 +270,9 @@ pub enum Expr<'a> {
     /// An expression followed by `!``
     Suffixed(&'a Expr<'a>),
 
+    /// An expression followed by `?`, e.g. `File.read path?`
+    TrySuffixed(&'a Expr<'a>),
+
     RecordUpdate {
         update: &'a Loc<Expr<'a>>,
         fields: Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>,
@@ -1571,7 +1574,7 @@ impl<'a> Malformed for Expr<'a> {
             PrecedenceConflict(_) |
             MultipleRecordBuilders(_) |
             UnappliedRecordBuilder(_) => true,
-            Suffixed(expr) => expr.is_malformed(),
+            Suffixed(expr) | TrySuffixed(expr) => expr.is_malformed(),
         }
     }
 }
@@ -7,13 +7,13 @@ use crate::blankspace::{
     space0_after_e, space0_around_e_no_after_indent_check, space0_around_ee, space0_before_e,
     space0_before_optional_after, space0_e, spaces, spaces_around, spaces_before,
 };
-use crate::ident::{integer_ident, lowercase_ident, parse_ident, Accessor, Ident};
+use crate::ident::{chomp_while, integer_ident, lowercase_ident, parse_ident, Accessor, Ident};
 use crate::keyword;
 use crate::parser::{
-    self, backtrackable, increment_min_indent, line_min_indent, optional, reset_min_indent,
-    sep_by1, sep_by1_e, set_min_indent, specialize, specialize_ref, then, word1, word1_indent,
-    word2, EClosure, EExpect, EExpr, EIf, EInParens, EList, ENumber, EPattern, ERecord, EString,
-    EType, EWhen, Either, ParseResult, Parser,
+    self, backtrackable, depth_limited, increment_min_indent, lazy, line_min_indent, optional,
+    reset_min_indent, sep_by1, sep_by1_e, set_min_indent, specialize, specialize_ref, then, word1,
+    word1_indent, word2, EClosure, EExpect, EExpr, EIf, EInParens, EList, ENumber, EPattern,
+    ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
 };
 use crate::pattern::{closure_param, loc_implements_parser};
 use crate::state::State;
@@ -70,11 +70,9 @@ pub struct ExprParseOptions {
 }
 
 pub fn expr_help<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
-    move |arena, state: State<'a>, min_indent: u32| {
-        loc_expr(true)
-            .parse(arena, state, min_indent)
-            .map(|(a, b, c)| (a, b.value, c))
-    }
+    map!(lazy(|| loc_expr(true)), |loc_expr: Loc<Expr<'a>>| {
+        loc_expr.value
+    })
 }
 
 fn loc_expr_in_parens_help<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>> {
@@ -297,15 +295,19 @@ fn unary_negate<'a>() -> impl Parser<'a, (), EExpr<'a>> {
 }
 
 fn expr_start<'a>(options: ExprParseOptions) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
-    one_of![
-        loc!(specialize(EExpr::If, if_expr_help(options))),
-        loc!(specialize(EExpr::When, when::expr_help(options))),
-        loc!(specialize(EExpr::Expect, expect_help(options))),
-        loc!(specialize(EExpr::Dbg, dbg_help(options))),
-        loc!(specialize(EExpr::Closure, closure_help(options))),
-        loc!(expr_operator_chain(options)),
-        fail_expr_start_e()
-    ]
+    // Every nested expression (parens, list elements, closure bodies, etc.) goes through here
+    depth_limited(
+        one_of![
+            loc!(specialize(EExpr::If, if_expr_help(options))),
+            loc!(specialize(EExpr::When, when::expr_help(options))),
+            loc!(specialize(EExpr::Expect, expect_help(options))),
+            loc!(specialize(EExpr::Dbg, dbg_help(options))),
+            loc!(specialize(EExpr::Closure, closure_help(options))),
+            loc!(expr_operator_chain(options)),
+            fail_expr_start_e()
+        ],
+        EExpr::TooDeep,
+    )
     .trace("expr_start")
 }
 
@@ -321,6 +323,13 @@ fn expr_operator_chain<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a
                         Loc::at(expr.region, Expr::Suffixed(arena.alloc(expr.value))),
                         state.advance(1),
                     )
+                } else if state.bytes().starts_with(b"?") {
+                    // If the next thing after the expression is a `?`, then it's TrySuffixed
+                    (
+                        progress,
+                        Loc::at(expr.region, Expr::TrySuffixed(arena.alloc(expr.value))),
+                        state.advance(1),
+                    )
                 } else {
                     (progress, expr, state)
                 }
@@ -1837,6 +1846,13 @@ fn parse_expr_end<'a>(
                 Expr::Suffixed(arena.alloc(expr)),
                 state.advance(1),
             )
+        } else if state.bytes().starts_with(b"?") {
+            // If the next thing after the expression is a `?`, then it's TrySuffixed
+            (
+                progress,
+                Expr::TrySuffixed(arena.alloc(expr)),
+                state.advance(1),
+            )
         } else {
             (progress, expr, state)
         }
@@ -1964,12 +1980,13 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::UnappliedRecordBuilder { .. }
         | Expr::RecordUpdate { .. }
         | Expr::UnaryOp(_, _)
+        | Expr::Suffixed(_)
+        | Expr::TrySuffixed(_)
         | Expr::Crash => return Err(()),
 
         Expr::Str(string) => Pattern::StrLiteral(string),
         Expr::SingleQuote(string) => Pattern::SingleQuote(string),
         Expr::MalformedIdent(string, problem) => Pattern::MalformedIdent(string, problem),
-        Expr::Suffixed(_) => todo!(),
     };
 
     // Now we re-add the spaces
@@ -3023,23 +3040,13 @@ where
         }
         "<-" => good!(BinOp::Backpassing, 2),
         "!" => Err((NoProgress, to_error("!", state.pos()))),
+        "?" => Err((NoProgress, to_error("?", state.pos()))),
         _ => bad_made_progress!(chomped),
     }
 }
 
 fn chomp_ops(bytes: &[u8]) -> &str {
-    let mut chomped = 0;
-
-    for c in bytes.iter() {
-        if let Some(true) = BINOP_CHAR_MASK.get(*c as usize) {
-            chomped += 1;
-        } else {
-            break;
-        }
-    }
-
-    unsafe {
-        // Safe because BINOP_CHAR_SET only contains ascii chars
-        std::str::from_utf8_unchecked(&bytes[..chomped])
-    }
+    chomp_while(bytes, |ch| {
+        matches!(BINOP_CHAR_MASK.get(ch as usize), Some(true))
+    })
 }
@@ -233,20 +233,50 @@ fn malformed_identifier<'a>(
 
 /// skip forward to the next non-identifier character
 pub fn chomp_malformed(bytes: &[u8]) -> usize {
+    // We can't use ch.is_alphanumeric() here because that passes for
+    // things that are "numeric" but not ASCII digits, like `¾`
+    chomp_while(bytes, |ch| {
+        ch == '.' || ch == '_' || ch.is_alphabetic() || ch.is_ascii_digit()
+    })
+    .len()
+}
+
+/// Chomp the longest prefix of `buffer` whose characters all satisfy `is_good`,
+/// returning it as a `&str` borrowed from the buffer. Callers advance the state
+/// by its length.
+pub(crate) fn chomp_while<F>(buffer: &[u8], mut is_good: F) -> &str
+where
+    F: FnMut(char) -> bool,
+{
     use encode_unicode::CharExt;
+
     let mut chomped = 0;
-    while let Ok((ch, width)) = char::from_utf8_slice_start(&bytes[chomped..]) {
-        // We can't use ch.is_alphanumeric() here because that passes for
-        // things that are "numeric" but not ASCII digits, like `¾`
-        if ch == '.' || ch == '_' || ch.is_alphabetic() || ch.is_ascii_digit() {
+
+    loop {
+        // Source text is almost always ASCII, so check single bytes directly
+        // and only decode UTF-8 when we hit a multi-byte character.
+        let (ch, width) = match buffer.get(chomped) {
+            Some(&byte) if byte.is_ascii() => (byte as char, 1),
+            Some(_) => match char::from_utf8_slice_start(&buffer[chomped..]) {
+                Ok(decoded) => decoded,
+                Err(_) => break,
+            },
+            None => break,
+        };
+
+        if is_good(ch) {
             chomped += width;
-            continue;
         } else {
             break;
         }
     }
 
-    chomped
+    // SAFETY: `chomped` only ever grows by the width of a char that was just
+    // decoded from `buffer[chomped..]`, either a single ASCII byte or a full
+    // multi-byte sequence that `from_utf8_slice_start` validated. So
+    // `buffer[..chomped]` is a run of whole, valid UTF-8 chars, and the cut
+    // falls on a char boundary.
+    unsafe { std::str::from_utf8_unchecked(&buffer[..chomped]) }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -295,7 +325,7 @@ fn chomp_integer_part(buffer: &[u8]) -> Result<&str, Progress> {
     )
 }
 
-fn is_plausible_ident_continue(ch: char) -> bool {
+pub(crate) fn is_plausible_ident_continue(ch: char) -> bool {
     ch == '_' || is_alnum(ch)
 }
 
@@ -317,14 +347,7 @@ where
         }
     }
 
-    while let Ok((ch, width)) = char::from_utf8_slice_start(&buffer[chomped..]) {
-        if rest_is_good(ch) {
-            chomped += width;
-        } else {
-            // we're done
-            break;
-        }
-    }
+    chomped += chomp_while(&buffer[chomped..], rest_is_good).len();
 
     if let Ok((next, _width)) = char::from_utf8_slice_start(&buffer[chomped..]) {
         // This would mean we have e.g.:
@@ -660,3 +683,21 @@ fn chomp_access_chain<'a>(buffer: &'a [u8], parts: &mut Vec<'a, Accessor<'a>>) -
         Ok(chomped as u32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chomp_while_stops_at_first_bad_char() {
+        assert_eq!(chomp_while(b"abc1 def", is_alnum), "abc1");
+        assert_eq!(chomp_while(b" abc", is_alnum), "");
+        assert_eq!(chomp_while(b"", is_alnum), "");
+    }
+
+    #[test]
+    fn chomp_while_keeps_multibyte_chars_whole() {
+        assert_eq!(chomp_while("héllo wörld".as_bytes(), is_alnum), "héllo");
+        assert_eq!(chomp_while("ab¾".as_bytes(), is_alnum), "ab");
+    }
+}
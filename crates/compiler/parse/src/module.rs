@@ -36,6 +36,21 @@ pub fn module_defs<'a>() -> impl Parser<'a, Defs<'a>, SyntaxError<'a>> {
     )
 }
 
+/// Parse a complete module: its header, then every top-level def through to the end of the file.
+pub fn parse_module<'a>(
+    arena: &'a bumpalo::Bump,
+    src: &'a str,
+) -> Result<(Module<'a>, Defs<'a>), SyntaxError<'a>> {
+    let (module, state) = parse_header(arena, State::new(src.as_bytes()))
+        .map_err(|fail| SyntaxError::Header(fail.problem))?;
+
+    let (_, defs, _) = module_defs()
+        .parse(arena, state, 0)
+        .map_err(|(_, fail)| fail)?;
+
+    Ok((module, defs))
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
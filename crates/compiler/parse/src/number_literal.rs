@@ -1,4 +1,5 @@
 use crate::ast::Base;
+use crate::ident::chomp_while;
 use crate::parser::{ENumber, ParseResult, Parser, Progress};
 use crate::state::State;
 
@@ -113,49 +114,28 @@ fn chomp_number_dec<'a>(
     ))
 }
 
-fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
-    let start_bytes_len = bytes.len();
+fn chomp_number(bytes: &[u8]) -> (bool, usize) {
     let mut is_float = false;
-
-    while let Some(byte) = bytes.first() {
-        match byte {
-            b'.' => {
-                // skip, fix multiple `.`s in canonicalization
-                is_float = true;
-                bytes = &bytes[1..];
-            }
-            b'e' => {
-                // maybe scientific notation?
-                match bytes.get(1) {
-                    Some(b'-') => {
-                        is_float = true;
-                        bytes = &bytes[2..];
-                    }
-                    Some(c) if (*c as char).is_ascii_digit() => {
-                        is_float = true;
-                        bytes = &bytes[2..];
-                    }
-                    _ => {
-                        bytes = &bytes[1..];
-                    }
-                }
-            }
-            b'_' => {
-                // skip
-                bytes = &bytes[1..];
-            }
-            _ if byte.is_ascii_digit() || byte.is_ascii_alphabetic() => {
-                // valid digits (alphabetic in hex digits, and the `e` in `12e26` scientific notation
-                bytes = &bytes[1..];
-            }
-            _ => {
-                // not a valid digit; we're done
-                return (is_float, start_bytes_len - bytes.len());
-            }
+    let mut prev = None;
+
+    let chomped = chomp_while(bytes, |ch| {
+        let is_good = match ch {
+            // skip, fix multiple `.`s in canonicalization
+            '.' => true,
+            // maybe scientific notation, e.g. `1e-3`
+            '-' => prev == Some('e'),
+            // valid digits (alphabetic in hex digits, and the `e` in `12e26` scientific notation)
+            _ => ch == '_' || ch.is_ascii_digit() || ch.is_ascii_alphabetic(),
+        };
+
+        if ch == '.' || (prev == Some('e') && (ch == '-' || ch.is_ascii_digit())) {
+            is_float = true;
         }
-    }
 
-    // if the above loop exits, we must be dealing with an empty slice
-    // therefore we parsed all of the bytes in the input
-    (is_float, start_bytes_len)
+        prev = Some(ch);
+
+        is_good
+    });
+
+    (is_float, chomped.len())
 }
@@ -1,3 +1,4 @@
+use crate::ident::is_plausible_ident_continue;
 use crate::state::State;
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
@@ -63,6 +64,30 @@ pub enum SyntaxError<'a> {
     Space(BadInputError),
     NotEndOfFile(Position),
 }
+
+impl<'a> SyntaxError<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Option<Region> {
+        match self {
+            SyntaxError::Unexpected(region)
+            | SyntaxError::Eof(region)
+            | SyntaxError::ReservedKeyword(region)
+            | SyntaxError::ArgumentsBeforeEquals(region) => Some(*region),
+            SyntaxError::OutdentedTooFar
+            | SyntaxError::InvalidPattern
+            | SyntaxError::BadUtf8
+            | SyntaxError::NotYetImplemented(_)
+            | SyntaxError::Todo
+            | SyntaxError::Space(_) => None,
+            SyntaxError::Type(problem) => Some(problem.get_region()),
+            SyntaxError::Pattern(problem) => Some(problem.get_region()),
+            SyntaxError::Expr(problem, _) => Some(problem.get_region()),
+            SyntaxError::Header(problem) => Some(problem.get_region()),
+            SyntaxError::NotEndOfFile(pos) => Some(Region::from_pos(*pos)),
+        }
+    }
+}
+
 pub trait SpaceProblem: std::fmt::Debug {
     fn space_problem(e: BadInputError, pos: Position) -> Self;
 }
@@ -132,6 +157,29 @@ pub enum EHeader<'a> {
     InconsistentModuleName(Region),
 }
 
+impl<'a> EHeader<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EHeader::Provides(problem, _) => problem.get_region(),
+            EHeader::Exposes(problem, _) => problem.get_region(),
+            EHeader::Imports(problem, _) => problem.get_region(),
+            EHeader::Requires(problem, _) => problem.get_region(),
+            EHeader::Packages(problem, _) => problem.get_region(),
+            EHeader::Generates(problem, _) => problem.get_region(),
+            EHeader::GeneratesWith(problem, _) => problem.get_region(),
+            EHeader::Space(_, pos)
+            | EHeader::Start(pos)
+            | EHeader::ModuleName(pos)
+            | EHeader::IndentStart(pos) => Region::from_pos(*pos),
+            EHeader::AppName(problem, _) => problem.get_region(),
+            EHeader::PackageName(problem, _) => problem.get_region(),
+            EHeader::PlatformName(problem, _) => problem.get_region(),
+            EHeader::InconsistentModuleName(region) => *region,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EProvides<'a> {
     Provides(Position),
@@ -148,6 +196,26 @@ pub enum EProvides<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EProvides<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EProvides::Provides(pos)
+            | EProvides::Open(pos)
+            | EProvides::To(pos)
+            | EProvides::IndentProvides(pos)
+            | EProvides::IndentTo(pos)
+            | EProvides::IndentListStart(pos)
+            | EProvides::IndentPackage(pos)
+            | EProvides::ListStart(pos)
+            | EProvides::ListEnd(pos)
+            | EProvides::Identifier(pos)
+            | EProvides::Space(_, pos) => Region::from_pos(*pos),
+            EProvides::Package(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EExposes {
     Exposes(Position),
@@ -160,6 +228,22 @@ pub enum EExposes {
     Space(BadInputError, Position),
 }
 
+impl EExposes {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExposes::Exposes(pos)
+            | EExposes::Open(pos)
+            | EExposes::IndentExposes(pos)
+            | EExposes::IndentListStart(pos)
+            | EExposes::ListStart(pos)
+            | EExposes::ListEnd(pos)
+            | EExposes::Identifier(pos)
+            | EExposes::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ERequires<'a> {
     Requires(Position),
@@ -173,6 +257,23 @@ pub enum ERequires<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ERequires<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERequires::Requires(pos)
+            | ERequires::Open(pos)
+            | ERequires::IndentRequires(pos)
+            | ERequires::IndentListStart(pos)
+            | ERequires::ListStart(pos)
+            | ERequires::ListEnd(pos)
+            | ERequires::Rigid(pos)
+            | ERequires::Space(_, pos) => Region::from_pos(*pos),
+            ERequires::TypedIdent(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypedIdent<'a> {
     Space(BadInputError, Position),
@@ -184,6 +285,21 @@ pub enum ETypedIdent<'a> {
     Identifier(Position),
 }
 
+impl<'a> ETypedIdent<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypedIdent::Space(_, pos)
+            | ETypedIdent::HasType(pos)
+            | ETypedIdent::IndentHasType(pos)
+            | ETypedIdent::Name(pos)
+            | ETypedIdent::IndentType(pos)
+            | ETypedIdent::Identifier(pos) => Region::from_pos(*pos),
+            ETypedIdent::Type(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackages<'a> {
     Open(Position),
@@ -197,6 +313,23 @@ pub enum EPackages<'a> {
     PackageEntry(EPackageEntry<'a>, Position),
 }
 
+impl<'a> EPackages<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackages::Open(pos)
+            | EPackages::Space(_, pos)
+            | EPackages::Packages(pos)
+            | EPackages::IndentPackages(pos)
+            | EPackages::ListStart(pos)
+            | EPackages::ListEnd(pos)
+            | EPackages::IndentListStart(pos)
+            | EPackages::IndentListEnd(pos) => Region::from_pos(*pos),
+            EPackages::PackageEntry(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageName<'a> {
     BadPath(EString<'a>, Position),
@@ -204,6 +337,16 @@ pub enum EPackageName<'a> {
     Multiline(Position),
 }
 
+impl<'a> EPackageName<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageName::BadPath(problem, _) => problem.get_region(),
+            EPackageName::Escapes(pos) | EPackageName::Multiline(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageEntry<'a> {
     BadPackage(EPackageName<'a>, Position),
@@ -213,6 +356,19 @@ pub enum EPackageEntry<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EPackageEntry<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageEntry::BadPackage(problem, _) => problem.get_region(),
+            EPackageEntry::Shorthand(pos)
+            | EPackageEntry::Colon(pos)
+            | EPackageEntry::IndentPackage(pos)
+            | EPackageEntry::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EImports {
     Open(Position),
@@ -236,6 +392,33 @@ pub enum EImports {
     StrLiteral(Position),
 }
 
+impl EImports {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EImports::Open(pos)
+            | EImports::Imports(pos)
+            | EImports::IndentImports(pos)
+            | EImports::IndentListStart(pos)
+            | EImports::IndentListEnd(pos)
+            | EImports::ListStart(pos)
+            | EImports::ListEnd(pos)
+            | EImports::Identifier(pos)
+            | EImports::ExposingDot(pos)
+            | EImports::ShorthandDot(pos)
+            | EImports::Shorthand(pos)
+            | EImports::ModuleName(pos)
+            | EImports::Space(_, pos)
+            | EImports::IndentSetStart(pos)
+            | EImports::SetStart(pos)
+            | EImports::SetEnd(pos)
+            | EImports::TypedIdent(pos)
+            | EImports::AsKeyword(pos)
+            | EImports::StrLiteral(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGenerates {
     Open(Position),
@@ -247,6 +430,21 @@ pub enum EGenerates {
     IndentTypeEnd(Position),
 }
 
+impl EGenerates {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGenerates::Open(pos)
+            | EGenerates::Generates(pos)
+            | EGenerates::IndentGenerates(pos)
+            | EGenerates::Identifier(pos)
+            | EGenerates::Space(_, pos)
+            | EGenerates::IndentTypeStart(pos)
+            | EGenerates::IndentTypeEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGeneratesWith {
     Open(Position),
@@ -260,6 +458,23 @@ pub enum EGeneratesWith {
     Space(BadInputError, Position),
 }
 
+impl EGeneratesWith {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGeneratesWith::Open(pos)
+            | EGeneratesWith::With(pos)
+            | EGeneratesWith::IndentWith(pos)
+            | EGeneratesWith::IndentListStart(pos)
+            | EGeneratesWith::IndentListEnd(pos)
+            | EGeneratesWith::ListStart(pos)
+            | EGeneratesWith::ListEnd(pos)
+            | EGeneratesWith::Identifier(pos)
+            | EGeneratesWith::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BadInputError {
     HasTab,
@@ -364,6 +579,60 @@ pub enum EExpr<'a> {
 
     IndentStart(Position),
     IndentEnd(Position),
+
+    TooDeep(Position),
+}
+
+impl<'a> EExpr<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpr::TrailingOperator(pos)
+            | EExpr::Start(pos)
+            | EExpr::End(pos)
+            | EExpr::BadExprEnd(pos)
+            | EExpr::Space(_, pos)
+            | EExpr::Dot(pos)
+            | EExpr::Access(pos)
+            | EExpr::UnaryNot(pos)
+            | EExpr::UnaryNegate(pos)
+            | EExpr::BadOperator(_, pos)
+            | EExpr::DefMissingFinalExpr(pos)
+            | EExpr::IndentDefBody(pos)
+            | EExpr::IndentEquals(pos)
+            | EExpr::IndentAnnotation(pos)
+            | EExpr::Equals(pos)
+            | EExpr::Colon(pos)
+            | EExpr::DoubleColon(pos)
+            | EExpr::Ident(pos)
+            | EExpr::MalformedPattern(pos)
+            | EExpr::QualifiedTag(pos)
+            | EExpr::BackpassComma(pos)
+            | EExpr::BackpassArrow(pos)
+            | EExpr::Underscore(pos)
+            | EExpr::Crash(pos)
+            | EExpr::Number(_, pos)
+            | EExpr::IndentStart(pos)
+            | EExpr::IndentEnd(pos)
+            | EExpr::TooDeep(pos) => Region::from_pos(*pos),
+            EExpr::DefMissingFinalExpr2(problem, _) => problem.get_region(),
+            EExpr::Type(problem, _) => problem.get_region(),
+            EExpr::Pattern(problem, _) => problem.get_region(),
+            EExpr::Ability(problem, _) => problem.get_region(),
+            EExpr::ElmStyleFunction(region, _)
+            | EExpr::OptionalValueInRecordBuilder(region)
+            | EExpr::RecordUpdateBuilder(region) => *region,
+            EExpr::When(problem, _) => problem.get_region(),
+            EExpr::If(problem, _) => problem.get_region(),
+            EExpr::Expect(problem, _) => problem.get_region(),
+            EExpr::Dbg(problem, _) => problem.get_region(),
+            EExpr::Closure(problem, _) => problem.get_region(),
+            EExpr::InParens(problem, _) => problem.get_region(),
+            EExpr::Record(problem, _) => problem.get_region(),
+            EExpr::Str(problem, _) => problem.get_region(),
+            EExpr::List(problem, _) => problem.get_region(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -391,6 +660,27 @@ pub enum EString<'a> {
     ExpectedDoubleQuoteGotSingleQuote(Position),
 }
 
+impl<'a> EString<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EString::Open(pos)
+            | EString::CodePtOpen(pos)
+            | EString::CodePtEnd(pos)
+            | EString::InvalidSingleQuote(_, pos)
+            | EString::Space(_, pos)
+            | EString::EndlessSingleLine(pos)
+            | EString::EndlessMultiLine(pos)
+            | EString::EndlessSingleQuote(pos)
+            | EString::UnknownEscape(pos)
+            | EString::FormatEnd(pos)
+            | EString::MultilineInsufficientIndent(pos)
+            | EString::ExpectedDoubleQuoteGotSingleQuote(pos) => Region::from_pos(*pos),
+            EString::Format(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ESingleQuote {
     Empty,
@@ -416,6 +706,24 @@ pub enum ERecord<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ERecord<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERecord::End(pos)
+            | ERecord::Open(pos)
+            | ERecord::Updateable(pos)
+            | ERecord::Field(pos)
+            | ERecord::Colon(pos)
+            | ERecord::QuestionMark(pos)
+            | ERecord::Arrow(pos)
+            | ERecord::Ampersand(pos)
+            | ERecord::Space(_, pos) => Region::from_pos(*pos),
+            ERecord::Expr(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EInParens<'a> {
     End(Position),
@@ -431,6 +739,19 @@ pub enum EInParens<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EInParens<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EInParens::End(pos)
+            | EInParens::Open(pos)
+            | EInParens::Empty(pos)
+            | EInParens::Space(_, pos) => Region::from_pos(*pos),
+            EInParens::Expr(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EClosure<'a> {
     Space(BadInputError, Position),
@@ -446,6 +767,24 @@ pub enum EClosure<'a> {
     IndentArg(Position),
 }
 
+impl<'a> EClosure<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EClosure::Space(_, pos)
+            | EClosure::Start(pos)
+            | EClosure::Arrow(pos)
+            | EClosure::Comma(pos)
+            | EClosure::Arg(pos)
+            | EClosure::IndentArrow(pos)
+            | EClosure::IndentBody(pos)
+            | EClosure::IndentArg(pos) => Region::from_pos(*pos),
+            EClosure::Pattern(problem, _) => problem.get_region(),
+            EClosure::Body(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EList<'a> {
     Open(Position),
@@ -455,6 +794,16 @@ pub enum EList<'a> {
     Expr(&'a EExpr<'a>, Position),
 }
 
+impl<'a> EList<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EList::Open(pos) | EList::End(pos) | EList::Space(_, pos) => Region::from_pos(*pos),
+            EList::Expr(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EWhen<'a> {
     Space(BadInputError, Position),
@@ -478,6 +827,30 @@ pub enum EWhen<'a> {
     PatternAlignment(u32, Position),
 }
 
+impl<'a> EWhen<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EWhen::Space(_, pos)
+            | EWhen::When(pos)
+            | EWhen::Is(pos)
+            | EWhen::Arrow(pos)
+            | EWhen::Bar(pos)
+            | EWhen::IfToken(pos)
+            | EWhen::IndentCondition(pos)
+            | EWhen::IndentPattern(pos)
+            | EWhen::IndentArrow(pos)
+            | EWhen::IndentBranch(pos)
+            | EWhen::IndentIfGuard(pos)
+            | EWhen::PatternAlignment(_, pos) => Region::from_pos(*pos),
+            EWhen::Pattern(problem, _) => problem.get_region(),
+            EWhen::IfGuard(problem, _) => problem.get_region(),
+            EWhen::Condition(problem, _) => problem.get_region(),
+            EWhen::Branch(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EAbility<'a> {
     Space(BadInputError, Position),
@@ -488,6 +861,19 @@ pub enum EAbility<'a> {
     DemandColon(Position),
 }
 
+impl<'a> EAbility<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EAbility::Space(_, pos)
+            | EAbility::DemandAlignment(_, pos)
+            | EAbility::DemandName(pos)
+            | EAbility::DemandColon(pos) => Region::from_pos(*pos),
+            EAbility::Type(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EIf<'a> {
     Space(BadInputError, Position),
@@ -507,6 +893,27 @@ pub enum EIf<'a> {
     IndentElseBranch(Position),
 }
 
+impl<'a> EIf<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EIf::Space(_, pos)
+            | EIf::If(pos)
+            | EIf::Then(pos)
+            | EIf::Else(pos)
+            | EIf::IndentCondition(pos)
+            | EIf::IndentIf(pos)
+            | EIf::IndentThenToken(pos)
+            | EIf::IndentElseToken(pos)
+            | EIf::IndentThenBranch(pos)
+            | EIf::IndentElseBranch(pos) => Region::from_pos(*pos),
+            EIf::Condition(problem, _) => problem.get_region(),
+            EIf::ThenBranch(problem, _) => problem.get_region(),
+            EIf::ElseBranch(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EExpect<'a> {
     Space(BadInputError, Position),
@@ -517,6 +924,20 @@ pub enum EExpect<'a> {
     IndentCondition(Position),
 }
 
+impl<'a> EExpect<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpect::Space(_, pos)
+            | EExpect::Dbg(pos)
+            | EExpect::Expect(pos)
+            | EExpect::IndentCondition(pos) => Region::from_pos(*pos),
+            EExpect::Condition(problem, _) => problem.get_region(),
+            EExpect::Continuation(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPattern<'a> {
     Record(PRecord<'a>, Position),
@@ -538,6 +959,32 @@ pub enum EPattern<'a> {
     AsIndentStart(Position),
 
     AccessorFunction(Position),
+
+    TooDeep(Position),
+}
+
+impl<'a> EPattern<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPattern::Record(problem, _) => problem.get_region(),
+            EPattern::List(problem, _) => problem.get_region(),
+            EPattern::AsKeyword(pos)
+            | EPattern::AsIdentifier(pos)
+            | EPattern::Underscore(pos)
+            | EPattern::NotAPattern(pos)
+            | EPattern::Start(pos)
+            | EPattern::End(pos)
+            | EPattern::Space(_, pos)
+            | EPattern::NumLiteral(_, pos)
+            | EPattern::IndentStart(pos)
+            | EPattern::IndentEnd(pos)
+            | EPattern::AsIndentStart(pos)
+            | EPattern::AccessorFunction(pos)
+            | EPattern::TooDeep(pos) => Region::from_pos(*pos),
+            EPattern::PInParens(problem, _) => problem.get_region(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -555,6 +1002,22 @@ pub enum PRecord<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PRecord<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            PRecord::End(pos)
+            | PRecord::Open(pos)
+            | PRecord::Field(pos)
+            | PRecord::Colon(pos)
+            | PRecord::Optional(pos)
+            | PRecord::Space(_, pos) => Region::from_pos(*pos),
+            PRecord::Pattern(problem, _) => problem.get_region(),
+            PRecord::Expr(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PList<'a> {
     End(Position),
@@ -566,6 +1029,18 @@ pub enum PList<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PList<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            PList::End(pos) | PList::Open(pos) | PList::Rest(pos) | PList::Space(_, pos) => {
+                Region::from_pos(*pos)
+            }
+            PList::Pattern(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PInParens<'a> {
     Empty(Position),
@@ -576,6 +1051,19 @@ pub enum PInParens<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PInParens<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            PInParens::Empty(pos)
+            | PInParens::End(pos)
+            | PInParens::Open(pos)
+            | PInParens::Space(_, pos) => Region::from_pos(*pos),
+            PInParens::Pattern(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EType<'a> {
     Space(BadInputError, Position),
@@ -600,6 +1088,36 @@ pub enum EType<'a> {
     TIndentStart(Position),
     TIndentEnd(Position),
     TAsIndentStart(Position),
+
+    TTooDeep(Position),
+}
+
+impl<'a> EType<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            EType::Space(_, pos)
+            | EType::UnderscoreSpacing(pos)
+            | EType::TBadTypeVariable(pos)
+            | EType::TWildcard(pos)
+            | EType::TInferred(pos)
+            | EType::TStart(pos)
+            | EType::TEnd(pos)
+            | EType::TFunctionArgument(pos)
+            | EType::TWhereBar(pos)
+            | EType::TImplementsClause(pos)
+            | EType::TIndentStart(pos)
+            | EType::TIndentEnd(pos)
+            | EType::TAsIndentStart(pos)
+            | EType::TTooDeep(pos) => Region::from_pos(*pos),
+            EType::TRecord(problem, _) => problem.get_region(),
+            EType::TTagUnion(problem, _) => problem.get_region(),
+            EType::TInParens(problem, _) => problem.get_region(),
+            EType::TApply(problem, _) => problem.get_region(),
+            EType::TInlineAlias(problem, _) => problem.get_region(),
+            EType::TAbilityImpl(problem, _) => problem.get_region(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -620,6 +1138,25 @@ pub enum ETypeRecord<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeRecord<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeRecord::End(pos)
+            | ETypeRecord::Open(pos)
+            | ETypeRecord::Field(pos)
+            | ETypeRecord::Colon(pos)
+            | ETypeRecord::Optional(pos)
+            | ETypeRecord::Space(_, pos)
+            | ETypeRecord::IndentOpen(pos)
+            | ETypeRecord::IndentColon(pos)
+            | ETypeRecord::IndentOptional(pos)
+            | ETypeRecord::IndentEnd(pos) => Region::from_pos(*pos),
+            ETypeRecord::Type(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeTagUnion<'a> {
     End(Position),
@@ -630,6 +1167,18 @@ pub enum ETypeTagUnion<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ETypeTagUnion<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeTagUnion::End(pos) | ETypeTagUnion::Open(pos) | ETypeTagUnion::Space(_, pos) => {
+                Region::from_pos(*pos)
+            }
+            ETypeTagUnion::Type(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInParens<'a> {
     /// e.g. (), which isn't a valid type
@@ -647,6 +1196,21 @@ pub enum ETypeInParens<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeInParens<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInParens::Empty(pos)
+            | ETypeInParens::End(pos)
+            | ETypeInParens::Open(pos)
+            | ETypeInParens::Space(_, pos)
+            | ETypeInParens::IndentOpen(pos)
+            | ETypeInParens::IndentEnd(pos) => Region::from_pos(*pos),
+            ETypeInParens::Type(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeApply {
     ///
@@ -659,6 +1223,20 @@ pub enum ETypeApply {
     StartIsNumber(Position),
 }
 
+impl ETypeApply {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeApply::StartNotUppercase(pos)
+            | ETypeApply::End(pos)
+            | ETypeApply::Space(_, pos)
+            | ETypeApply::DoubleDot(pos)
+            | ETypeApply::TrailingDot(pos)
+            | ETypeApply::StartIsNumber(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInlineAlias {
     NotAnAlias(Position),
@@ -666,6 +1244,17 @@ pub enum ETypeInlineAlias {
     ArgumentNotLowercase(Position),
 }
 
+impl ETypeInlineAlias {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInlineAlias::NotAnAlias(pos)
+            | ETypeInlineAlias::Qualified(pos)
+            | ETypeInlineAlias::ArgumentNotLowercase(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeAbilityImpl<'a> {
     End(Position),
@@ -687,6 +1276,28 @@ pub enum ETypeAbilityImpl<'a> {
     IndentAmpersand(Position),
 }
 
+impl<'a> ETypeAbilityImpl<'a> {
+    /// The region of the source code that this problem points at
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeAbilityImpl::End(pos)
+            | ETypeAbilityImpl::Open(pos)
+            | ETypeAbilityImpl::Field(pos)
+            | ETypeAbilityImpl::Colon(pos)
+            | ETypeAbilityImpl::Arrow(pos)
+            | ETypeAbilityImpl::Optional(pos)
+            | ETypeAbilityImpl::Space(_, pos)
+            | ETypeAbilityImpl::Updateable(pos)
+            | ETypeAbilityImpl::QuestionMark(pos)
+            | ETypeAbilityImpl::Ampersand(pos)
+            | ETypeAbilityImpl::IndentBar(pos)
+            | ETypeAbilityImpl::IndentAmpersand(pos) => Region::from_pos(*pos),
+            ETypeAbilityImpl::Type(problem, _) => problem.get_region(),
+            ETypeAbilityImpl::Expr(problem, _) => problem.get_region(),
+        }
+    }
+}
+
 impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
     fn from(e: ERecord<'a>) -> Self {
         match e {
@@ -875,6 +1486,8 @@ where
     E: 'a,
 {
     move |_, mut state: State<'a>, _min_indent| {
+        use encode_unicode::CharExt;
+
         let width = keyword.len();
 
         if !state.bytes().starts_with(keyword.as_bytes()) {
@@ -883,22 +1496,20 @@ where
 
         // the next character should not be an identifier character
         // to prevent treating `whence` or `iffy` as keywords
-        match state.bytes().get(width) {
-            Some(next) if *next == b' ' || *next == b'#' || *next == b'\n' || *next == b'\r' => {
-                state = state.advance(width);
-                Ok((MadeProgress, (), state))
+        match char::from_utf8_slice_start(&state.bytes()[width..]) {
+            Ok((next, _)) if is_plausible_ident_continue(next) => {
+                Err((NoProgress, if_error(state.pos())))
             }
-            None => {
+            _ => {
                 state = state.advance(width);
                 Ok((MadeProgress, (), state))
             }
-            Some(_) => Err((NoProgress, if_error(state.pos()))),
         }
     }
 }
 
-/// Parse zero or more values separated by a delimiter (e.g. a comma) whose
-/// values are discarded
+/// Parse zero or more values separated by a delimiter (e.g. a comma).
+/// The delimiter's output is discarded.
 pub fn sep_by0<'a, P, D, Val, Error>(
     delimiter: D,
     parser: P,
@@ -949,7 +1560,11 @@ where
                         }
                         Err((delim_progress, fail)) => match delim_progress {
                             MadeProgress => return Err((MadeProgress, fail)),
-                            NoProgress => return Ok((NoProgress, buf, state)),
+                            NoProgress => {
+                                let progress =
+                                    Progress::from_lengths(start_bytes_len, state.bytes().len());
+                                return Ok((progress, buf, state));
+                            }
                         },
                     }
                 }
@@ -962,8 +1577,8 @@ where
     }
 }
 
-/// Parse zero or more values separated by a delimiter (e.g. a comma)
-/// with an optional trailing delimiter whose values are discarded
+/// Parse zero or more values separated by a delimiter (e.g. a comma),
+/// allowing a trailing delimiter. The delimiter's output is discarded.
 pub fn trailing_sep_by0<'a, P, D, Val, Error>(
     delimiter: D,
     parser: P,
@@ -1011,7 +1626,11 @@ where
                         }
                         Err((delim_progress, fail)) => match delim_progress {
                             MadeProgress => return Err((MadeProgress, fail)),
-                            NoProgress => return Ok((NoProgress, buf, state)),
+                            NoProgress => {
+                                let progress =
+                                    Progress::from_lengths(start_bytes_len, state.bytes().len());
+                                return Ok((progress, buf, state));
+                            }
                         },
                     }
                 }
@@ -1024,8 +1643,8 @@ where
     }
 }
 
-/// Parse one or more values separated by a delimiter (e.g. a comma) whose
-/// values are discarded
+/// Parse one or more values separated by a delimiter (e.g. a comma).
+/// The delimiter's output is discarded.
 pub fn sep_by1<'a, P, D, Val, Error>(
     delimiter: D,
     parser: P,
@@ -1084,8 +1703,9 @@ where
     }
 }
 
-/// Parse one or more values separated by a delimiter (e.g. a comma) whose
-/// values are discarded
+/// Parse one or more values separated by a delimiter (e.g. a comma),
+/// reporting a missing element with `to_element_error`.
+/// The delimiter's output is discarded.
 pub fn sep_by1_e<'a, P, V, D, Val, Error>(
     delimiter: D,
     parser: P,
@@ -1152,6 +1772,53 @@ where
     }
 }
 
+/// Parse zero or more items, stopping as soon as the terminator parses.
+/// Returns the items along with the terminator's output.
+///
+/// The terminator is tried before each item, so an input that could be read as
+/// either is treated as the end of the sequence.
+pub fn zero_or_more_until<'a, P, T, Item, End, Error>(
+    item: P,
+    terminator: T,
+) -> impl Parser<'a, (Vec<'a, Item>, End), Error>
+where
+    P: Parser<'a, Item, Error>,
+    T: Parser<'a, End, Error>,
+    Error: 'a,
+{
+    move |arena, state: State<'a>, min_indent: u32| {
+        let start_bytes_len = state.bytes().len();
+        let mut state = state;
+        let mut buf = Vec::new_in(arena);
+
+        loop {
+            match terminator.parse(arena, state.clone(), min_indent) {
+                Ok((_, end, next_state)) => {
+                    let progress =
+                        Progress::from_lengths(start_bytes_len, next_state.bytes().len());
+
+                    return Ok((progress, (buf, end), next_state));
+                }
+                Err((MadeProgress, fail)) => return Err((MadeProgress, fail)),
+                Err((NoProgress, _)) => match item.parse(arena, state.clone(), min_indent) {
+                    Ok((_, output, next_state)) => {
+                        // an item that consumes nothing would loop forever
+                        debug_assert!(next_state.bytes().len() < state.bytes().len());
+
+                        state = next_state;
+                        buf.push(output);
+                    }
+                    Err((item_progress, fail)) => {
+                        let progress = Progress::from_lengths(start_bytes_len, state.bytes().len());
+
+                        return Err((progress.or(item_progress), fail));
+                    }
+                },
+            }
+        }
+    }
+}
+
 pub fn optional<'a, P, T, E>(parser: P) -> impl Parser<'a, Option<T>, E>
 where
     P: Parser<'a, T, E>,
@@ -1834,6 +2501,62 @@ where
     map_with_arena!(parser, transform)
 }
 
+/// Builds the parser from `make` each time it runs, rather than once up front.
+///
+/// Recursive productions (an expression containing a parenthesized expression, a type
+/// containing a function type, ...) need this: constructing the inner parser eagerly would
+/// recurse forever, and an `impl Parser` that names itself can't be resolved. Pass the
+/// production's `fn` directly, e.g. `lazy(loc_pattern)`, so the parser's type stays finite.
+pub fn lazy<F>(make: F) -> Lazy<F> {
+    Lazy { make }
+}
+
+pub struct Lazy<F> {
+    make: F,
+}
+
+impl<'a, F, P, Val, Error> Parser<'a, Val, Error> for Lazy<F>
+where
+    F: Fn() -> P,
+    P: Parser<'a, Val, Error>,
+{
+    fn parse(
+        &self,
+        arena: &'a Bump,
+        state: State<'a>,
+        min_indent: u32,
+    ) -> ParseResult<'a, Val, Error> {
+        (self.make)().parse(arena, state, min_indent)
+    }
+}
+
+/// Wraps a production that can nest inside itself (expressions, patterns, types).
+///
+/// Once the parser is nested more than the state's maximum depth inside of these,
+/// this fails with `to_error` instead of letting pathological input like thousands
+/// of open parens overflow the stack.
+pub fn depth_limited<'a, P, Val, Error, F>(parser: P, to_error: F) -> impl Parser<'a, Val, Error>
+where
+    P: Parser<'a, Val, Error>,
+    F: Fn(Position) -> Error,
+    Error: 'a,
+{
+    move |arena: &'a Bump, mut state: State<'a>, min_indent: u32| {
+        if state.nesting_depth >= state.max_nesting_depth {
+            // Report this as progress, so that no alternative gets tried and masks the problem
+            return Err((MadeProgress, to_error(state.pos())));
+        }
+
+        state.nesting_depth += 1;
+
+        let (progress, output, mut state) = parser.parse(arena, state, min_indent)?;
+
+        state.nesting_depth -= 1;
+
+        Ok((progress, output, state))
+    }
+}
+
 pub fn backtrackable<'a, P, Val, Error>(parser: P) -> impl Parser<'a, Val, Error>
 where
     P: Parser<'a, Val, Error>,
@@ -4,8 +4,8 @@ use crate::ident::{lowercase_ident, parse_ident, Accessor, Ident};
 use crate::keyword;
 use crate::parser::Progress::{self, *};
 use crate::parser::{
-    self, backtrackable, fail_when, optional, specialize, specialize_ref, then, word1, word2,
-    word3, EPattern, PInParens, PList, PRecord, Parser,
+    self, backtrackable, depth_limited, fail_when, optional, specialize, specialize_ref, then,
+    word1, word2, word3, EPattern, PInParens, PList, PRecord, Parser,
 };
 use crate::state::State;
 use crate::string_literal::StrLikeLiteral;
@@ -44,6 +44,11 @@ pub fn closure_param<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
 }
 
 pub fn loc_pattern_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    // Every nested pattern (parens, list elements, record fields, etc.) goes through here
+    depth_limited(loc_pattern_as_help(), EPattern::TooDeep)
+}
+
+fn loc_pattern_as_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
     move |arena, state: State<'a>, min_indent| {
         let (_, pattern, state) = loc_pattern_help_help().parse(arena, state, min_indent)?;
 
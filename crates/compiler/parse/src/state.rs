@@ -21,8 +21,18 @@ pub struct State<'a> {
 
     /// Position of the first non-whitespace character on the current line
     pub(crate) line_start_after_whitespace: Position,
+
+    /// How many expressions, patterns and types the parser is currently nested inside of
+    pub(crate) nesting_depth: u32,
+
+    /// Nesting deeper than this fails with a parse error, rather than overflowing the stack
+    pub(crate) max_nesting_depth: u32,
 }
 
+/// Far deeper than any hand-written code nests, while still fitting in the stack
+/// of the threads the compiler parses on.
+pub const DEFAULT_MAX_NESTING_DEPTH: u32 = 256;
+
 impl<'a> State<'a> {
     pub fn new(bytes: &'a [u8]) -> State<'a> {
         State {
@@ -33,9 +43,19 @@ impl<'a> State<'a> {
             // Technically not correct.
             // We don't know the position of the first non-whitespace character yet.
             line_start_after_whitespace: Position::zero(),
+
+            nesting_depth: 0,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
         }
     }
 
+    /// Set how deeply expressions, patterns and types may nest before parsing fails
+    #[must_use]
+    pub const fn with_max_nesting_depth(mut self, max_nesting_depth: u32) -> State<'a> {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
     pub fn original_bytes(&self) -> &'a [u8] {
         self.original_bytes
     }
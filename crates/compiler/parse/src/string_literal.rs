@@ -39,7 +39,7 @@ fn consume_indent(mut state: State, mut indent: u32) -> Result<State, (Progress,
                 state.advance_mut(1);
                 indent -= 1;
             }
-            None | Some(b'\n') => {
+            None | Some(b'\n') | Some(b'\r') => {
                 break;
             }
             Some(_) => {
@@ -100,7 +100,7 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
             is_multiline = true;
             is_single_quote = false;
 
-            if state.consume_mut("\n") {
+            if state.consume_mut("\n") || state.consume_mut("\r\n") {
                 state = consume_indent(state, indent)?;
             }
         } else if state.consume_mut("\"") {
@@ -317,10 +317,31 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         state.advance(1),
                     ));
                 }
+                b'\r' if bytes.as_slice().first() != Some(&b'\n') => {
+                    // A carriage return is only allowed as part of a \r\n line ending.
+                    return Err((
+                        MadeProgress,
+                        EString::Space(
+                            BadInputError::HasMisplacedCarriageReturn,
+                            state.pos().bump_column(segment_parsed_bytes as u32 - 1),
+                        ),
+                    ));
+                }
                 b'\n' => {
                     if is_multiline {
-                        let without_newline = &state.bytes()[0..(segment_parsed_bytes - 1)];
-                        let with_newline = &state.bytes()[0..segment_parsed_bytes];
+                        let mut without_newline = &state.bytes()[0..(segment_parsed_bytes - 1)];
+                        let mut with_newline = &state.bytes()[0..segment_parsed_bytes];
+
+                        // Normalize \r\n to \n, so block strings in files with Windows
+                        // line endings have the same contents as they would on Unix.
+                        if let Some((b'\r', before_cr)) = without_newline.split_last() {
+                            let mut normalized = Vec::with_capacity_in(before_cr.len() + 1, arena);
+                            normalized.extend_from_slice(before_cr);
+                            normalized.push(b'\n');
+
+                            without_newline = before_cr;
+                            with_newline = normalized.into_bump_slice();
+                        }
 
                         state.advance_mut(segment_parsed_bytes);
                         state = consume_indent(state, indent)?;
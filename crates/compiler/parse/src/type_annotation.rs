@@ -12,8 +12,9 @@ use crate::parser::{
     absolute_column_min_indent, increment_min_indent, then, ERecord, ETypeAbilityImpl,
 };
 use crate::parser::{
-    allocated, backtrackable, fail, optional, specialize, specialize_ref, word, word1, word2,
-    EType, ETypeApply, ETypeInParens, ETypeInlineAlias, ETypeRecord, ETypeTagUnion, Parser,
+    allocated, backtrackable, depth_limited, fail, keyword_e, optional, specialize, specialize_ref,
+    word1, word2, EType, ETypeApply, ETypeInParens, ETypeInlineAlias, ETypeRecord, ETypeTagUnion,
+    Parser,
     Progress::{self, *},
 };
 use crate::state::State;
@@ -109,6 +110,11 @@ fn parse_type_alias_after_as<'a>() -> impl Parser<'a, TypeHeader<'a>, EType<'a>>
 }
 
 fn term<'a>(stop_at_surface_has: bool) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, EType<'a>> {
+    // Every nested type (parens, record fields, tag arguments, etc.) goes through here
+    depth_limited(term_help(stop_at_surface_has), EType::TTooDeep)
+}
+
+fn term_help<'a>(stop_at_surface_has: bool) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, EType<'a>> {
     map_with_arena!(
         and!(
             one_of!(
@@ -459,7 +465,7 @@ fn implements_clause<'a>() -> impl Parser<'a, Loc<ImplementsClause<'a>>, EType<'
             ),
             skip_first!(
                 // Parse "implements"; we don't care about this keyword
-                word(crate::keyword::IMPLEMENTS, EType::TImplementsClause),
+                keyword_e(crate::keyword::IMPLEMENTS, EType::TImplementsClause),
                 // Parse "Hash & ..."; this may be qualified from another module like "Hash.Hash"
                 absolute_column_min_indent(ability_chain())
             )
@@ -486,7 +492,7 @@ fn implements_clause_chain<'a>(
     move |arena, state: State<'a>, min_indent: u32| {
         let (_, (spaces_before, ()), state) = and!(
             space0_e(EType::TIndentStart),
-            word(crate::keyword::WHERE, EType::TWhereBar)
+            keyword_e(crate::keyword::WHERE, EType::TWhereBar)
         )
         .parse(arena, state, min_indent)?;
 
@@ -514,7 +520,7 @@ fn implements_clause_chain<'a>(
 pub fn implements_abilities<'a>() -> impl Parser<'a, Loc<ImplementsAbilities<'a>>, EType<'a>> {
     increment_min_indent(skip_first!(
         // Parse "implements"; we don't care about this keyword
-        word(crate::keyword::IMPLEMENTS, EType::TImplementsClause),
+        keyword_e(crate::keyword::IMPLEMENTS, EType::TImplementsClause),
         // Parse "Hash"; this may be qualified from another module like "Hash.Hash"
         space0_before_e(
             loc!(map!(
@@ -22,11 +22,14 @@ mod test_parse {
     use roc_parse::ast::StrSegment::*;
     use roc_parse::ast::{self, EscapedChar};
     use roc_parse::ast::{CommentOrNewline, StrLiteral::*};
-    use roc_parse::module::module_defs;
-    use roc_parse::parser::{Parser, SyntaxError};
+    use roc_parse::module::{module_defs, parse_module};
+    use roc_parse::parser::{
+        and, keyword_e, lazy, loc, optional, sep_by0, then, trailing_sep_by0, word1,
+        zero_or_more_until, EList, Parser, Progress, SyntaxError,
+    };
     use roc_parse::state::State;
-    use roc_parse::test_helpers::parse_expr_with;
-    use roc_region::all::{Loc, Region};
+    use roc_parse::test_helpers::{parse_expr_with, parse_loc_with};
+    use roc_region::all::{Loc, Position, Region};
     use std::{f64, i64};
 
     fn assert_parses_to<'a>(input: &'a str, expected_expr: Expr<'a>) {
@@ -304,6 +307,29 @@ mod test_parse {
         assert_parses_to(num.to_string().as_str(), Num(num.to_string().as_str()));
     }
 
+    #[quickcheck]
+    fn arbitrary_expr_input_does_not_panic(input: std::string::String) {
+        let arena = Bump::new();
+
+        if let Ok(loc_expr) = parse_loc_with(&arena, &input) {
+            assert!(loc_expr.region.end().offset as usize <= input.trim().len());
+        }
+    }
+
+    #[quickcheck]
+    fn arbitrary_module_input_does_not_panic(input: std::string::String) {
+        let arena = Bump::new();
+
+        let _ = parse_module(&arena, &input);
+    }
+
+    #[test]
+    fn suffixed_expr_is_not_a_pattern() {
+        let arena = Bump::new();
+
+        assert!(parse_expr_with(&arena, "x! = 1\nx").is_err());
+    }
+
     #[quickcheck]
     fn all_f64_values_parse(mut num: f64) {
         // NaN, Infinity, -Infinity (these would all parse as tags in Roc)
@@ -319,6 +345,13 @@ mod test_parse {
         assert_parses_to(float_string.as_str(), Float(float_string.as_str()));
     }
 
+    #[test]
+    fn scientific_notation() {
+        assert_parses_to("1e-3", Float("1e-3"));
+        assert_parses_to("12e26", Float("12e26"));
+        assert_parses_to("1_000e", Num("1_000e"));
+    }
+
     // SINGLE QUOTE LITERAL
     #[test]
     fn single_quote() {
@@ -405,6 +438,271 @@ mod test_parse {
         );
     }
 
+    #[test]
+    fn parse_block_string_with_crlf() {
+        let arena = Bump::new();
+        let unix = parse_expr_with(&arena, "\"\"\"\nfoo\n\nbar\n\"\"\"");
+        let windows = parse_expr_with(&arena, "\"\"\"\r\nfoo\r\n\r\nbar\r\n\"\"\"");
+
+        assert!(unix.is_ok());
+        assert_eq!(unix, windows);
+    }
+
+    #[test]
+    fn parse_string_with_bare_carriage_return() {
+        let arena = Bump::new();
+
+        assert!(parse_expr_with(&arena, "\"foo\rbar\"").is_err());
+        assert!(parse_expr_with(&arena, "\"\"\"\nfoo\rbar\n\"\"\"").is_err());
+    }
+
+    /// Release builds reach the default maximum nesting depth within the 2MB stack that
+    /// test threads get, but debug builds need closer to 16MB.
+    fn with_deep_stack<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+        std::thread::Builder::new()
+            .stack_size(64 * 1024 * 1024)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap()
+    }
+
+    #[test]
+    fn deeply_nested_input_fails_instead_of_overflowing() {
+        with_deep_stack(|| {
+            let arena = Bump::new();
+            let nested = |open: &str, inner: &str, close: &str, depth: usize| {
+                format!("{}{inner}{}", open.repeat(depth), close.repeat(depth))
+            };
+
+            let parens = nested("(", "1", ")", 100_000);
+            let records = nested("{ a: ", "1", " }", 100_000);
+            let patterns = format!("\\{} -> 1", nested("(", "x", ")", 100_000));
+            let types = format!("x : {}\nx", nested("List (", "a", ")", 100_000));
+
+            for src in [parens, records, patterns, types] {
+                let result = parse_expr_with(&arena, arena.alloc(src));
+
+                assert!(format!("{result:?}").contains("TooDeep("), "{result:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn realistic_nesting_parses() {
+        with_deep_stack(|| {
+            let arena = Bump::new();
+            let lists = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+            let backpassing = format!("{}x", "x <- f\n".repeat(200));
+
+            for src in [lists, backpassing] {
+                let result = parse_expr_with(&arena, arena.alloc(src));
+
+                assert!(result.is_ok(), "{result:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn max_nesting_depth_is_configurable() {
+        use roc_parse::parser::EExpr;
+
+        let arena = Bump::new();
+
+        // The outer list and each of the four elements are one level of nesting each
+        let parse = |max_nesting_depth| {
+            let state = State::new(b"[[[[1]]]]").with_max_nesting_depth(max_nesting_depth);
+
+            roc_parse::expr::test_parse_expr(0, &arena, state)
+        };
+
+        assert!(parse(5).is_ok());
+        assert_eq!(parse(0), Err(EExpr::TooDeep(Position::new(0))));
+        assert_eq!(
+            parse(4),
+            Err(EExpr::List(
+                EList::Expr(
+                    &EExpr::List(
+                        EList::Expr(
+                            &EExpr::List(
+                                EList::Expr(
+                                    &EExpr::List(
+                                        EList::Expr(
+                                            &EExpr::TooDeep(Position::new(4)),
+                                            Position::new(4)
+                                        ),
+                                        Position::new(3)
+                                    ),
+                                    Position::new(3)
+                                ),
+                                Position::new(2)
+                            ),
+                            Position::new(2)
+                        ),
+                        Position::new(1)
+                    ),
+                    Position::new(1)
+                ),
+                Position::new(0)
+            ))
+        );
+    }
+
+    fn element_regions(elements: &[Loc<()>]) -> std::vec::Vec<Region> {
+        elements.iter().map(|elem| elem.region).collect()
+    }
+
+    #[test]
+    fn sep_by0_keeps_every_element() {
+        let arena = Bump::new();
+        let parser = sep_by0(word1(b',', EList::End), loc(word1(b'x', EList::Open)));
+
+        let (progress, elements, state) = parser.parse(&arena, State::new(b"x,x,x]"), 0).unwrap();
+
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(
+            element_regions(&elements),
+            [
+                Region::new(Position::new(0), Position::new(1)),
+                Region::new(Position::new(2), Position::new(3)),
+                Region::new(Position::new(4), Position::new(5)),
+            ]
+        );
+        assert_eq!(state.pos(), Position::new(5));
+
+        let (progress, elements, state) = parser.parse(&arena, State::new(b"]"), 0).unwrap();
+
+        assert_eq!(progress, Progress::NoProgress);
+        assert!(elements.is_empty());
+        assert_eq!(state.pos(), Position::new(0));
+    }
+
+    #[test]
+    fn trailing_sep_by0_allows_trailing_delimiter() {
+        let arena = Bump::new();
+        let parser = trailing_sep_by0(word1(b',', EList::End), loc(word1(b'x', EList::Open)));
+
+        let (progress, elements, state) = parser.parse(&arena, State::new(b"x,x,]"), 0).unwrap();
+
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(
+            element_regions(&elements),
+            [
+                Region::new(Position::new(0), Position::new(1)),
+                Region::new(Position::new(2), Position::new(3)),
+            ]
+        );
+        assert_eq!(state.pos(), Position::new(4));
+
+        // Without a trailing delimiter, sep_by0 rejects it
+        let parser = sep_by0(word1(b',', EList::End), loc(word1(b'x', EList::Open)));
+
+        assert!(parser.parse(&arena, State::new(b"x,x,]"), 0).is_err());
+    }
+
+    #[test]
+    fn parse_module_reads_header_and_defs() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+            interface Foo exposes [x] imports []
+
+            x = 1
+            "#
+        );
+
+        let (module, defs) = parse_module(&arena, src).unwrap();
+
+        assert!(matches!(module.header, ast::Header::Interface(_)));
+        assert_eq!(defs.len(), 1);
+
+        // Without a header, the first def is reported as a header problem
+        assert!(matches!(
+            parse_module(&arena, "x = 1\n"),
+            Err(SyntaxError::Header(_))
+        ));
+    }
+
+    #[test]
+    fn zero_or_more_until_stops_at_terminator() {
+        let arena = Bump::new();
+        let parser = zero_or_more_until(loc(word1(b'x', EList::Open)), word1(b']', EList::End));
+
+        let (progress, (elements, ()), state) =
+            parser.parse(&arena, State::new(b"xxx]x"), 0).unwrap();
+
+        assert_eq!(progress, Progress::MadeProgress);
+        assert_eq!(elements.len(), 3);
+        assert_eq!(state.pos(), Position::new(4));
+
+        let (_, (elements, ()), state) = parser.parse(&arena, State::new(b"]"), 0).unwrap();
+
+        assert!(elements.is_empty());
+        assert_eq!(state.pos(), Position::new(1));
+
+        // Running out of items before the terminator is an error
+        assert_eq!(
+            parser.parse(&arena, State::new(b"xx"), 0).unwrap_err().0,
+            Progress::MadeProgress
+        );
+    }
+
+    #[test]
+    fn keyword_requires_word_boundary() {
+        let arena = Bump::new();
+        let parser = keyword_e("if", EList::Open);
+
+        for input in ["if", "if x", "if(x)", "if#comment", "if\n"] {
+            let (progress, (), state) = parser
+                .parse(&arena, State::new(input.as_bytes()), 0)
+                .unwrap();
+
+            assert_eq!(progress, Progress::MadeProgress, "{input:?}");
+            assert_eq!(state.pos(), Position::new(2), "{input:?}");
+        }
+
+        for input in ["iffy", "if2", "if_x", "ifé"] {
+            assert!(
+                matches!(
+                    parser.parse(&arena, State::new(input.as_bytes()), 0),
+                    Err((Progress::NoProgress, _))
+                ),
+                "{input:?}"
+            );
+        }
+    }
+
+    /// Counts how deeply a run of `()` pairs is nested, e.g. `(())` is 2.
+    fn nested_parens<'a>() -> impl Parser<'a, u32, EList<'a>> {
+        then(
+            optional(and(
+                word1(b'(', EList::Open),
+                and(lazy(nested_parens), word1(b')', EList::End)),
+            )),
+            |_arena, state, progress, parens| {
+                let depth = parens.map_or(0, |(_, (inner, _))| inner + 1);
+
+                Ok((progress, depth, state))
+            },
+        )
+    }
+
+    #[test]
+    fn lazy_allows_recursive_parsers() {
+        let arena = Bump::new();
+
+        let (_, depth, state) = nested_parens()
+            .parse(&arena, State::new(b"((()))"), 0)
+            .unwrap();
+
+        assert_eq!(depth, 3);
+        assert!(state.has_reached_end());
+
+        assert!(nested_parens()
+            .parse(&arena, State::new(b"(()"), 0)
+            .is_err());
+    }
+
     // PARSE ERROR
 
     // TODO this should be parse error, but isn't!
@@ -38,7 +38,9 @@ pub enum Problem {
     UnusedDef(Symbol, Region),
     UnusedImport(Symbol, Region),
     UnusedModuleImport(ModuleId, Region),
-    ExposedButNotDefined(Symbol),
+    /// The region is that of the name in the header's `exposes` or `provides`, when it
+    /// could be found there.
+    ExposedButNotDefined(Symbol, Option<Region>),
     UnknownGeneratesWith(Loc<Ident>),
     /// First symbol is the name of the closure with that argument
     /// Bool is whether the closure is anonymous
@@ -221,7 +223,7 @@ impl Problem {
             Problem::UnusedDef(_, _) => Warning,
             Problem::UnusedImport(_, _) => Warning,
             Problem::UnusedModuleImport(_, _) => Warning,
-            Problem::ExposedButNotDefined(_) => RuntimeError,
+            Problem::ExposedButNotDefined(_, _) => RuntimeError,
             Problem::UnknownGeneratesWith(_) => RuntimeError,
             Problem::UnusedArgument(_, _, _, _) => Warning,
             Problem::UnusedBranchDef(_, _) => Warning,
@@ -280,6 +282,69 @@ impl Problem {
         }
     }
 
+    /// A stable identifier for this kind of problem, e.g. for `roc check --json`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Problem::UnusedDef(..) => "unused-def",
+            Problem::UnusedImport(..) => "unused-import",
+            Problem::UnusedModuleImport(..) => "unused-module-import",
+            Problem::ExposedButNotDefined(..) => "exposed-but-not-defined",
+            Problem::UnknownGeneratesWith(..) => "unknown-generates-with",
+            Problem::UnusedArgument(..) => "unused-argument",
+            Problem::UnusedBranchDef(..) => "unused-branch-def",
+            Problem::PrecedenceProblem(..) => "precedence-problem",
+            Problem::UnsupportedPattern(..) => "unsupported-pattern",
+            Problem::Shadowing { .. } => "shadowing",
+            Problem::CyclicAlias(..) => "cyclic-alias",
+            Problem::BadRecursion(..) => "bad-recursion",
+            Problem::PhantomTypeArgument { .. } => "phantom-type-argument",
+            Problem::UnboundTypeVariable { .. } => "unbound-type-variable",
+            Problem::DuplicateRecordFieldValue { .. } => "duplicate-record-field-value",
+            Problem::DuplicateRecordFieldType { .. } => "duplicate-record-field-type",
+            Problem::InvalidOptionalValue { .. } => "invalid-optional-value",
+            Problem::DuplicateTag { .. } => "duplicate-tag",
+            Problem::RuntimeError(runtime_error) => runtime_error.code(),
+            Problem::SignatureDefMismatch { .. } => "signature-def-mismatch",
+            Problem::InvalidAliasRigid { .. } => "invalid-alias-rigid",
+            Problem::InvalidInterpolation(..) => "invalid-interpolation",
+            Problem::InvalidHexadecimal(..) => "invalid-hexadecimal",
+            Problem::InvalidUnicodeCodePt(..) => "invalid-unicode-code-pt",
+            Problem::NestedDatatype { .. } => "nested-datatype",
+            Problem::InvalidExtensionType { .. } => "invalid-extension-type",
+            Problem::AbilityHasTypeVariables { .. } => "ability-has-type-variables",
+            Problem::ImplementsClauseIsNotAbility { .. } => "implements-clause-is-not-ability",
+            Problem::IllegalImplementsClause { .. } => "illegal-implements-clause",
+            Problem::DuplicateImplementsAbility { .. } => "duplicate-implements-ability",
+            Problem::AbilityMemberMissingImplementsClause { .. } => {
+                "ability-member-missing-implements-clause"
+            }
+            Problem::AbilityMemberMultipleBoundVars { .. } => "ability-member-multiple-bound-vars",
+            Problem::AbilityNotOnToplevel { .. } => "ability-not-on-toplevel",
+            Problem::AbilityUsedAsType(..) => "ability-used-as-type",
+            Problem::NestedSpecialization(..) => "nested-specialization",
+            Problem::IllegalDerivedAbility(..) => "illegal-derived-ability",
+            Problem::ImplementationNotFound { .. } => "implementation-not-found",
+            Problem::NotAnAbilityMember { .. } => "not-an-ability-member",
+            Problem::OptionalAbilityImpl { .. } => "optional-ability-impl",
+            Problem::QualifiedAbilityImpl { .. } => "qualified-ability-impl",
+            Problem::AbilityImplNotIdent { .. } => "ability-impl-not-ident",
+            Problem::DuplicateImpl { .. } => "duplicate-impl",
+            Problem::NotAnAbility(..) => "not-an-ability",
+            Problem::ImplementsNonRequired { .. } => "implements-non-required",
+            Problem::DoesNotImplementAbility { .. } => "does-not-implement-ability",
+            Problem::NotBoundInAllPatterns { .. } => "not-bound-in-all-patterns",
+            Problem::NoIdentifiersIntroduced(..) => "no-identifiers-introduced",
+            Problem::OverloadedSpecialization { .. } => "overloaded-specialization",
+            Problem::UnnecessaryOutputWildcard { .. } => "unnecessary-output-wildcard",
+            Problem::MultipleListRestPattern { .. } => "multiple-list-rest-pattern",
+            Problem::BadTypeArguments { .. } => "bad-type-arguments",
+            Problem::UnappliedCrash { .. } => "unapplied-crash",
+            Problem::OverAppliedCrash { .. } => "over-applied-crash",
+            Problem::DefsOnlyUsedInRecursion(..) => "defs-only-used-in-recursion",
+            Problem::FileProblem { .. } => "file-problem",
+        }
+    }
+
     /// Returns a Region value from the Problem, if possible.
     /// Some problems have more than one region; in those cases,
     /// this tries to pick the one that's closest to the original
@@ -367,6 +432,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::DegenerateBranch(region))
             | Problem::RuntimeError(RuntimeError::MultipleRecordBuilders(region))
             | Problem::RuntimeError(RuntimeError::UnappliedRecordBuilder(region))
+            | Problem::RuntimeError(RuntimeError::MisplacedTrySuffix(region))
             | Problem::InvalidAliasRigid { region, .. }
             | Problem::InvalidInterpolation(region)
             | Problem::InvalidHexadecimal(region)
@@ -417,6 +483,7 @@ impl Problem {
             | Problem::OverAppliedCrash { region }
             | Problem::UnappliedCrash { region }
             | Problem::DefsOnlyUsedInRecursion(_, region) => Some(*region),
+            Problem::ExposedButNotDefined(_, region) => *region,
             Problem::RuntimeError(RuntimeError::CircularDef(cycle_entries))
             | Problem::BadRecursion(cycle_entries) => {
                 cycle_entries.first().map(|entry| entry.expr_region)
@@ -428,10 +495,71 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::VoidValue)
             | Problem::RuntimeError(RuntimeError::ExposedButNotDefined(_))
             | Problem::RuntimeError(RuntimeError::NoImplementationNamed { .. })
-            | Problem::FileProblem { .. }
-            | Problem::ExposedButNotDefined(_) => None,
+            | Problem::FileProblem { .. } => None,
         }
     }
+
+    /// The other places a problem points at, besides its [Problem::region];
+    /// e.g. both sites of a shadowed name, or the earlier of two duplicate fields.
+    pub fn related_regions(&self) -> Vec<Region> {
+        let regions = match self {
+            Problem::Shadowing {
+                original_region,
+                shadow,
+                ..
+            }
+            | Problem::RuntimeError(RuntimeError::Shadowing {
+                original_region,
+                shadow,
+                ..
+            }) => vec![*original_region, shadow.region],
+            Problem::DuplicateRecordFieldValue {
+                field_region,
+                replaced_region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldType {
+                field_region,
+                replaced_region,
+                ..
+            } => vec![*field_region, *replaced_region],
+            Problem::DuplicateTag {
+                tag_region,
+                replaced_region,
+                ..
+            } => vec![*tag_region, *replaced_region],
+            Problem::InvalidOptionalValue { field_region, .. }
+            | Problem::RuntimeError(RuntimeError::InvalidOptionalValue { field_region, .. }) => {
+                vec![*field_region]
+            }
+            Problem::DuplicateImpl {
+                original,
+                duplicate,
+            } => vec![*original, *duplicate],
+            Problem::SignatureDefMismatch {
+                annotation_pattern,
+                def_pattern,
+            } => vec![*annotation_pattern, *def_pattern],
+            Problem::NestedDatatype {
+                def_region,
+                differing_recursion_region,
+                ..
+            } => vec![*def_region, *differing_recursion_region],
+            Problem::RuntimeError(RuntimeError::CircularDef(cycle_entries))
+            | Problem::BadRecursion(cycle_entries) => cycle_entries
+                .iter()
+                .map(|entry| entry.expr_region)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let primary = self.region();
+
+        regions
+            .into_iter()
+            .filter(|region| Some(*region) != primary)
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -612,6 +740,9 @@ pub enum RuntimeError {
 
     MultipleRecordBuilders(Region),
     UnappliedRecordBuilder(Region),
+
+    /// A `?` that is not the whole body of a def in a block, e.g. `foo bar?`
+    MisplacedTrySuffix(Region),
 }
 
 impl RuntimeError {
@@ -628,6 +759,47 @@ impl RuntimeError {
         }
     }
 
+    /// A stable identifier for this kind of problem, e.g. for `roc check --json`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RuntimeError::Shadowing { .. } => "shadowing",
+            RuntimeError::InvalidOptionalValue { .. } => "invalid-optional-value",
+            RuntimeError::UnsupportedPattern(..) => "unsupported-pattern",
+            RuntimeError::MalformedPattern(..) => "malformed-pattern",
+            RuntimeError::UnresolvedTypeVar => "unresolved-type-var",
+            RuntimeError::ErroneousType => "erroneous-type",
+            RuntimeError::LookupNotInScope { .. } => "lookup-not-in-scope",
+            RuntimeError::OpaqueNotDefined { .. } => "opaque-not-defined",
+            RuntimeError::OpaqueOutsideScope { .. } => "opaque-outside-scope",
+            RuntimeError::OpaqueNotApplied(..) => "opaque-not-applied",
+            RuntimeError::OpaqueAppliedToMultipleArgs(..) => "opaque-applied-to-multiple-args",
+            RuntimeError::ValueNotExposed { .. } => "value-not-exposed",
+            RuntimeError::ModuleNotImported { .. } => "module-not-imported",
+            RuntimeError::InvalidPrecedence(..) => "invalid-precedence",
+            RuntimeError::MalformedIdentifier(..) => "malformed-identifier",
+            RuntimeError::MalformedTypeName(..) => "malformed-type-name",
+            RuntimeError::MalformedClosure(..) => "malformed-closure",
+            RuntimeError::InvalidRecordUpdate { .. } => "invalid-record-update",
+            RuntimeError::InvalidFloat(..) => "invalid-float",
+            RuntimeError::InvalidInt(..) => "invalid-int",
+            RuntimeError::CircularDef(..) => "circular-def",
+            RuntimeError::NonExhaustivePattern => "non-exhaustive-pattern",
+            RuntimeError::InvalidInterpolation(..) => "invalid-interpolation",
+            RuntimeError::InvalidHexadecimal(..) => "invalid-hexadecimal",
+            RuntimeError::InvalidUnicodeCodePt(..) => "invalid-unicode-code-pt",
+            RuntimeError::NoImplementationNamed { .. } => "no-implementation-named",
+            RuntimeError::NoImplementation => "no-implementation",
+            RuntimeError::VoidValue => "void-value",
+            RuntimeError::ExposedButNotDefined(..) => "exposed-but-not-defined",
+            RuntimeError::EmptySingleQuote(..) => "empty-single-quote",
+            RuntimeError::MultipleCharsInSingleQuote(..) => "multiple-chars-in-single-quote",
+            RuntimeError::DegenerateBranch(..) => "degenerate-branch",
+            RuntimeError::MultipleRecordBuilders(..) => "multiple-record-builders",
+            RuntimeError::UnappliedRecordBuilder(..) => "unapplied-record-builder",
+            RuntimeError::MisplacedTrySuffix(..) => "misplaced-try-suffix",
+        }
+    }
+
     pub fn region(&self) -> Region {
         match self {
             RuntimeError::Shadowing { shadow, .. } => shadow.region,
@@ -655,6 +827,7 @@ impl RuntimeError {
             | RuntimeError::InvalidHexadecimal(region)
             | RuntimeError::MultipleRecordBuilders(region)
             | RuntimeError::UnappliedRecordBuilder(region)
+            | RuntimeError::MisplacedTrySuffix(region)
             | RuntimeError::InvalidUnicodeCodePt(region) => *region,
             RuntimeError::UnresolvedTypeVar | RuntimeError::ErroneousType => Region::zero(),
             RuntimeError::LookupNotInScope { loc_name, .. } => loc_name.region,
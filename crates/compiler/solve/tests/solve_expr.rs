@@ -5128,4 +5128,50 @@ mod solve_expr {
             r"{ bi128 : I128 -> I128, bi16 : I16 -> I16, bi32 : I32 -> I32, bi64 : I64 -> I64, bi8 : I8 -> I8, bu128 : U128 -> U128, bu16 : U16 -> U16, bu32 : U32 -> U32, bu64 : U64 -> U64, bu8 : U8 -> U8, dec : Dec -> Dec, f32 : F32 -> F32, f64 : F64 -> F64, fdec : Dec -> Dec, ff32 : F32 -> F32, ff64 : F64 -> F64, i128 : I128 -> I128, i16 : I16 -> I16, i32 : I32 -> I32, i64 : I64 -> I64, i8 : I8 -> I8, u128 : U128 -> U128, u16 : U16 -> U16, u32 : U32 -> U32, u64 : U64 -> U64, u8 : U8 -> U8 }",
         )
     }
+
+    #[test]
+    fn try_suffixed_def() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                parse : Str -> Result U64 [Empty]
+                parse = \s -> if s == "" then Err Empty else Ok 1
+
+                run =
+                    a = parse "a"?
+                    b : U64
+                    b = parse "bc"?
+
+                    Ok (a + b)
+
+                run
+                "#
+            ),
+            "[Err [Empty], Ok U64]",
+        )
+    }
+
+    #[test]
+    fn try_suffixed_def_out_of_order() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                parse : Str -> Result U64 [Empty]
+                parse = \s -> if s == "" then Err Empty else Ok 1
+
+                run =
+                    double = \n -> n * factor
+                    total = double a + offset
+                    a = parse "a"?
+                    factor = 2
+                    offset = 1
+
+                    Ok total
+
+                run
+                "#
+            ),
+            "[Err [Empty], Ok U64]",
+        )
+    }
 }
@@ -57,6 +57,25 @@ impl TypeError {
         }
     }
 
+    /// A stable identifier for this kind of problem, e.g. for `roc check --json`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TypeError::BadExpr(..) => "type-mismatch",
+            TypeError::BadPattern(..) => "pattern-type-mismatch",
+            TypeError::CircularType(..) => "circular-type",
+            TypeError::CircularDef(..) => "circular-def",
+            TypeError::UnexposedLookup(..) => "unexposed-lookup",
+            TypeError::UnfulfilledAbility(..) => "unfulfilled-ability",
+            TypeError::BadExprMissingAbility(..) => "missing-ability",
+            TypeError::BadPatternMissingAbility(..) => "pattern-missing-ability",
+            TypeError::Exhaustive(..) => "exhaustiveness",
+            TypeError::StructuralSpecialization { .. } => "structural-specialization",
+            TypeError::WrongSpecialization { .. } => "wrong-specialization",
+            TypeError::IngestedFileBadUtf8(..) => "ingested-file-bad-utf8",
+            TypeError::IngestedFileUnsupportedType(..) => "ingested-file-unsupported-type",
+        }
+    }
+
     pub fn region(&self) -> Option<Region> {
         match self {
             TypeError::BadExpr(region, ..)
@@ -92,6 +92,7 @@ fn create_llvm_module<'a>(
         Ok(x) => x,
         Err(LoadMonomorphizedError::LoadingProblem(roc_load::LoadingProblem::FormattedReport(
             report,
+            _,
         ))) => {
             println!("{report}");
             panic!();
@@ -140,7 +141,7 @@ fn create_llvm_module<'a>(
                 | UnusedModuleImport(_, _)
                 | RuntimeError(_)
                 | UnsupportedPattern(_, _)
-                | ExposedButNotDefined(_) => {
+                | ExposedButNotDefined(_, _) => {
                     let report = can_problem(&alloc, &line_info, module_path.clone(), problem);
                     let mut buf = String::new();
 
@@ -2,9 +2,8 @@ use bumpalo::Bump;
 use roc_fmt::{annotation::Formattable, module::fmt_module};
 use roc_parse::{
     ast::{Defs, Expr, Malformed, Module},
-    module::module_defs,
-    parser::{Parser, SyntaxError},
-    state::State,
+    module::parse_module,
+    parser::SyntaxError,
     test_helpers::{parse_defs_with, parse_expr_with, parse_header_with},
 };
 use roc_test_utils::assert_multiline_str_eq;
@@ -163,16 +162,7 @@ impl<'a> Input<'a> {
             }
 
             Input::Full(input) => {
-                let state = State::new(input.as_bytes());
-
-                let min_indent = 0;
-                let (_, header, state) = roc_parse::module::header()
-                    .parse(arena, state.clone(), min_indent)
-                    .map_err(|(_, fail)| SyntaxError::Header(fail))?;
-
-                let (_, module_defs, _state) = module_defs()
-                    .parse(arena, state, min_indent)
-                    .map_err(|(_, fail)| fail)?;
+                let (header, module_defs) = parse_module(arena, input)?;
 
                 Ok(Output::Full {
                     header,
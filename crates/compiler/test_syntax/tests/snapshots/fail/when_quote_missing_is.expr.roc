@@ -0,0 +1 @@
+when"x"
@@ -0,0 +1 @@
+crash "boom"
\ No newline at end of file
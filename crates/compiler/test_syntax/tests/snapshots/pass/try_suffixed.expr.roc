@@ -0,0 +1,3 @@
+a = parse "a"?
+b = List.first a?
+Ok b
\ No newline at end of file
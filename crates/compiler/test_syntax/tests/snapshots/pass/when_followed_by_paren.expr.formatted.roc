@@ -0,0 +1,2 @@
+when x is
+    _ -> 1
\ No newline at end of file
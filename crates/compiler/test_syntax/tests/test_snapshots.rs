@@ -200,6 +200,7 @@ mod test_snapshots {
         fail/if_guard_without_condition.expr,
         fail/if_missing_else.expr,
         fail/if_outdented_then.expr,
+        fail/if_paren_missing_then.expr,
         fail/imports_missing_comma.header,
         fail/inline_hastype.expr,
         fail/invalid_operator.expr,
@@ -246,6 +247,7 @@ mod test_snapshots {
         fail/when_outdented_branch.expr,
         fail/when_over_indented_int.expr,
         fail/when_over_indented_underscore.expr,
+        fail/when_quote_missing_is.expr,
         fail/where_type_variable.expr,
         fail/wild_case_arrow.expr,
         malformed/bad_opaque_ref.expr,
@@ -325,6 +327,8 @@ mod test_snapshots {
         pass/if_def.expr,
         pass/int_with_underscore.expr,
         pass/interface_with_newline.header,
+        pass/keyword_followed_by_paren.expr,
+        pass/keyword_followed_by_quote.expr,
         pass/lambda_in_chain.expr,
         pass/lambda_indent.expr,
         pass/list_closing_indent_not_enough.expr,
@@ -446,6 +450,7 @@ mod test_snapshots {
         pass/tag_pattern.expr,
         pass/ten_times_eleven.expr,
         pass/three_arg_closure.expr,
+        pass/try_suffixed.expr,
         pass/tuple_access_after_ident.expr,
         pass/tuple_access_after_record.expr,
         pass/tuple_accessor_function.expr,
@@ -473,6 +478,7 @@ mod test_snapshots {
         pass/var_minus_two.expr,
         pass/var_then.expr,
         pass/var_when.expr,
+        pass/when_followed_by_paren.expr,
         pass/when_if_guard.expr,
         pass/when_in_assignment.expr,
         pass/when_in_function.expr,
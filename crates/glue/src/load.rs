@@ -12,13 +12,17 @@ use roc_build::{
 use roc_collections::MutMap;
 use roc_error_macros::todo_lambda_erasure;
 use roc_gen_llvm::run_roc::RocCallResult;
-use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{
+    ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading,
+    UnresolvedHostType,
+};
 use roc_mono::ir::{generate_glue_procs, CrashTag, GlueProc, OptLevel};
 use roc_mono::layout::{GlobalLayoutInterner, LayoutCache, LayoutInterner};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
 use roc_target::{Architecture, TargetInfo};
 use roc_types::subs::{Subs, Variable};
+use roc_types::types::ErrorType;
 use std::fs::File;
 use std::io::{self, ErrorKind, Write};
 use std::mem::ManuallyDrop;
@@ -57,6 +61,7 @@ pub fn generate(
                 opt_level: OptLevel::Development,
                 emit_debug_info: false,
                 emit_llvm_ir: false,
+                emit_asm: false,
                 fuzz: false,
             };
 
@@ -204,6 +209,10 @@ pub fn generate(
                 eprintln!("Platform module file not found: {}", input_path.display());
                 process::exit(1);
             }
+            ErrorKind::InvalidData => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
             error => {
                 eprintln!(
                     "Error loading platform module file {} - {:?}",
@@ -403,16 +412,7 @@ pub fn load_types(
     // TODO the function kind may need to be parameterizable.
     let function_kind = FunctionKind::LambdaSet;
     let arena = &Bump::new();
-    let LoadedModule {
-        module_id: home,
-        mut can_problems,
-        mut type_problems,
-        mut declarations_by_id,
-        mut solved,
-        interns,
-        exposed_to_host,
-        ..
-    } = roc_load::load_and_typecheck(
+    let loaded_module = roc_load::load_and_typecheck(
         arena,
         full_file_path,
         RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
@@ -426,7 +426,7 @@ pub fn load_types(
         },
     )
     .unwrap_or_else(|problem| match problem {
-        LoadingProblem::FormattedReport(report) => {
+        LoadingProblem::FormattedReport(report, _) => {
             eprintln!("{report}");
 
             process::exit(1);
@@ -436,6 +436,18 @@ pub fn load_types(
         }
     });
 
+    let host_exposed_types = loaded_module.host_exposed_types();
+    let LoadedModule {
+        module_id: home,
+        mut can_problems,
+        mut type_problems,
+        mut declarations_by_id,
+        mut solved,
+        interns,
+        exposed_to_host,
+        ..
+    } = loaded_module;
+
     let decls = declarations_by_id.remove(&home).unwrap();
     let subs = solved.inner_mut();
 
@@ -450,6 +462,33 @@ pub fn load_types(
         );
     }
 
+    match host_exposed_types {
+        Ok(host_exposed_types) => {
+            if let Some((symbol, _)) = host_exposed_types
+                .iter()
+                .find(|(_, typ)| contains_tuple(typ))
+            {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "The type of `{}` contains a tuple, and I can't generate glue for tuples yet.\n\nTry using a record instead.",
+                        symbol.as_str(&interns)
+                    ),
+                ));
+            }
+        }
+        Err(UnresolvedHostType { symbol, variable }) => {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "The type of `{}` still contains the type variable `{}`, so I can't generate glue for it.\n\nThe host needs to know exactly what every type is. Try adding a type annotation that fills in the type variable with a concrete type.",
+                    symbol.as_str(&interns),
+                    variable
+                ),
+            ));
+        }
+    }
+
     // Get the variables for all the exposed_to_host symbols
     let variables = (0..decls.len()).filter_map(|index| {
         let symbol = decls.symbols[index].value;
@@ -537,3 +576,22 @@ pub fn load_types(
 
     Ok(arch_types)
 }
+
+/// Glue can't describe tuples to the host yet
+fn contains_tuple(typ: &ErrorType) -> bool {
+    use ErrorType::*;
+
+    match typ {
+        Tuple(..) => true,
+        Type(_, types) | Range(types) => types.iter().any(contains_tuple),
+        Alias(_, _, real, _) => contains_tuple(real),
+        Function(args, _, ret) => args.iter().any(contains_tuple) || contains_tuple(ret),
+        Record(fields, _) => fields
+            .values()
+            .any(|field| contains_tuple(field.as_inner())),
+        TagUnion(tags, _, _) | RecursiveTagUnion(_, tags, _, _) => {
+            tags.values().flatten().any(contains_tuple)
+        }
+        FlexVar(_) | RigidVar(_) | FlexAbleVar(..) | RigidAbleVar(..) | Infinite | Error => false,
+    }
+}
@@ -8,7 +8,7 @@ use roc_builtins::bitcode::{
     IntWidth::{self, *},
 };
 use roc_collections::{MutMap, VecMap};
-use roc_error_macros::todo_lambda_erasure;
+use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::{
     ident::TagName,
     symbol::{Interns, Symbol},
@@ -1339,10 +1339,10 @@ fn add_type_help<'a>(
         | Content::RigidVar(_)
         | Content::FlexAbleVar(_, _)
         | Content::RigidAbleVar(_, _) => {
-            todo!("TODO give a nice error message for a non-concrete type being passed to the host")
+            internal_error!("load_types rejects host-exposed types that contain type variables")
         }
         Content::Structure(FlatType::Tuple(..)) => {
-            todo!();
+            internal_error!("load_types rejects host-exposed types that contain tuples")
         }
         Content::Structure(FlatType::Record(fields, ext)) => {
             let it = fields
@@ -15,6 +15,20 @@ pub fn fixtures_dir(dir_name: &str) -> PathBuf {
     path
 }
 
+#[allow(dead_code)]
+pub fn known_bad_file(file_name: &str) -> PathBuf {
+    let mut path = root_dir();
+
+    // Descend into glue/tests/known_bad/{file_name}
+    path.push("crates");
+    path.push("glue");
+    path.push("tests");
+    path.push("known_bad");
+    path.push(file_name);
+
+    path
+}
+
 #[allow(dead_code)]
 pub fn root_dir() -> PathBuf {
     let mut path = env::current_exe().ok().unwrap();
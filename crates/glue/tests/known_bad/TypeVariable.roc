@@ -0,0 +1,9 @@
+platform "test-platform"
+    requires {} { main : Str }
+    exposes []
+    packages {}
+    imports []
+    provides [mainForHost]
+
+mainForHost : { name : Str, items : List a }
+mainForHost = { name: main, items: [] }
@@ -11,7 +11,7 @@ mod helpers;
 
 #[cfg(test)]
 mod glue_cli_run {
-    use crate::helpers::fixtures_dir;
+    use crate::helpers::{fixtures_dir, known_bad_file, root_dir};
     use cli_utils::helpers::{has_error, run_glue, run_roc, Out};
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -168,6 +168,32 @@ mod glue_cli_run {
         "#),
     }
 
+    #[test]
+    fn type_variable_exposed_to_host() {
+        let platform = known_bad_file("TypeVariable.roc");
+        let rust_glue_spec = root_dir()
+            .join("crates")
+            .join("glue")
+            .join("src")
+            .join("RustGlue.roc");
+
+        let glue_out = run_glue([
+            "glue",
+            rust_glue_spec.to_str().unwrap(),
+            platform.with_file_name("test_glue").to_str().unwrap(),
+            platform.to_str().unwrap(),
+        ]);
+
+        assert_eq!(glue_out.status.code(), Some(1));
+        assert!(
+            glue_out.stderr.contains(
+                "The type of `mainForHost` still contains the type variable `a`, so I can't generate glue for it."
+            ),
+            "unexpected stderr: {}",
+            glue_out.stderr
+        );
+    }
+
     fn check_for_tests(all_fixtures: &mut roc_collections::VecSet<String>) {
         use roc_collections::VecSet;
 
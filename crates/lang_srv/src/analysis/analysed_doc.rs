@@ -14,7 +14,7 @@ use tower_lsp::lsp_types::{
 
 use crate::{
     analysis::completion::{field_completion, get_completion_items, get_module_completion_items},
-    convert::{ToRange, ToRocPosition},
+    convert::{ToRange, ToRegion, ToRocPosition},
 };
 
 use super::{
@@ -98,6 +98,23 @@ impl DocInfo {
         }
     }
 
+    pub fn format_range(&self, range: Range) -> Option<Vec<TextEdit>> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse(arena, source).ok()?;
+        let (region, fmt) = ast.fmt_range(source, range.to_region(&self.line_info))?;
+        let original = &source[region.start().offset as usize..region.end().offset as usize];
+
+        if original == fmt.as_str() {
+            None
+        } else {
+            let range = region.to_range(&self.line_info);
+            let text_edit = TextEdit::new(range, fmt.to_string());
+            Some(vec![text_edit])
+        }
+    }
+
     pub fn semantic_tokens(&self) -> Option<SemanticTokensResult> {
         let source = &self.source;
         let arena = &Bump::new();
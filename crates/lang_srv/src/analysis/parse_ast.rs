@@ -2,9 +2,10 @@ use bumpalo::Bump;
 use roc_fmt::Buf;
 use roc_parse::{
     ast::{Defs, Module},
-    parser::SyntaxError,
+    parser::{Parser, SyntaxError},
+    state::State,
 };
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Region};
 
 use self::format::FormattedAst;
 
@@ -20,16 +21,7 @@ pub struct Ast<'a> {
 
 impl<'a> Ast<'a> {
     pub fn parse(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
-        use roc_parse::{
-            module::{module_defs, parse_header},
-            parser::Parser,
-            state::State,
-        };
-
-        let (module, state) = parse_header(arena, State::new(src.as_bytes()))
-            .map_err(|e| SyntaxError::Header(e.problem))?;
-
-        let (_, defs, _) = module_defs().parse(arena, state, 0).map_err(|(_, e)| e)?;
+        let (module, defs) = roc_parse::module::parse_module(arena, src)?;
 
         Ok(Ast {
             module,
@@ -50,6 +42,35 @@ impl<'a> Ast<'a> {
         FormattedAst::new(buf)
     }
 
+    /// Formats only the top-level defs that overlap `range`, returning the region they span
+    /// in `src` along with their formatted replacement. Returns `None` if no def overlaps.
+    pub fn fmt_range(&self, src: &'a str, range: Region) -> Option<(Region, FormattedAst<'a>)> {
+        // An empty range is a cursor, which selects the def it sits in. Otherwise the end is
+        // exclusive, so selecting whole lines doesn't also pull in the def on the next line.
+        let mut overlapping = self.defs.regions.iter().filter(|region| {
+            if range.is_empty() {
+                region.contains_pos(range.start())
+            } else {
+                region.start() < range.end() && range.start() < region.end()
+            }
+        });
+
+        let first = overlapping.next()?;
+        let last = overlapping.last().unwrap_or(first);
+        let region = Region::span_across(first, last);
+
+        let slice = &src[region.start().offset as usize..region.end().offset as usize];
+        let (_, defs, _) = roc_parse::module::module_defs()
+            .parse(self.arena, State::new(slice.as_bytes()), 0)
+            .ok()?;
+
+        let mut buf = Buf::new_in(self.arena);
+
+        roc_fmt::def::fmt_defs(&mut buf, &defs, 0);
+
+        Some((region, FormattedAst::new(buf)))
+    }
+
     pub fn semantic_tokens(&self) -> impl IntoIterator<Item = Loc<Token>> + '_ {
         let header_tokens = self.module.iter_tokens(self.arena);
         let body_tokens = self.defs.iter_tokens(self.arena);
@@ -694,7 +694,7 @@ impl IterTokens for Loc<Expr<'_>> {
             Expr::SpaceBefore(e, _) | Expr::SpaceAfter(e, _) => {
                 Loc::at(region, *e).iter_tokens(arena)
             }
-            Expr::ParensAround(e) => Loc::at(region, *e).iter_tokens(arena),
+            Expr::ParensAround(e) | Expr::TrySuffixed(e) => Loc::at(region, *e).iter_tokens(arena),
             Expr::MultipleRecordBuilders(e) => e.iter_tokens(arena),
             Expr::UnappliedRecordBuilder(e) => e.iter_tokens(arena),
             Expr::MalformedIdent(_, _) | Expr::MalformedClosure | Expr::PrecedenceConflict(_) => {
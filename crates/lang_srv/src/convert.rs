@@ -42,7 +42,7 @@ impl ToRegion for Range {
             },
             end: LineColumn {
                 line: self.end.line,
-                column: self.end.line,
+                column: self.end.character,
             },
         };
 
@@ -140,7 +140,7 @@ pub(crate) mod diag {
                 LoadingProblem::TriedToImportAppModule => {
                     "Attempted to import app module".to_string()
                 }
-                LoadingProblem::FormattedReport(report) => report.clone(),
+                LoadingProblem::FormattedReport(report, _) => report.clone(),
                 LoadingProblem::ImportCycle(_, _) => {
                     "Circular dependency between modules".to_string()
                 }
@@ -83,6 +83,11 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let document_range_formatting_provider = DocumentRangeFormattingOptions {
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        };
         let semantic_tokens_provider =
             SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
                 work_done_progress_options: WorkDoneProgressOptions {
@@ -108,6 +113,9 @@ impl RocServer {
             hover_provider: Some(hover_provider),
             definition_provider: Some(OneOf::Right(definition_provider)),
             document_formatting_provider: Some(OneOf::Right(document_formatting_provider)),
+            document_range_formatting_provider: Some(OneOf::Right(
+                document_range_formatting_provider,
+            )),
             semantic_tokens_provider: Some(semantic_tokens_provider),
             completion_provider: Some(completion_provider),
             ..ServerCapabilities::default()
@@ -290,6 +298,25 @@ impl LanguageServer for RocServer {
         unwind_async(self.state.registry.formatting(&text_document.uri)).await
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentRangeFormattingParams {
+            text_document,
+            range,
+            options: _,
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .range_formatting(&text_document.uri, range),
+        )
+        .await
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -594,4 +621,43 @@ mod tests {
         "#]]
         .assert_debug_eq(&actual);
     }
+
+    #[tokio::test]
+    async fn test_range_formatting() {
+        let doc = DOC_LIT.to_string()
+            + indoc! {r"
+            first   =   1
+
+            second   =   2
+
+            third   =   3
+            "};
+        let (inner, url) = test_setup(doc).await;
+
+        // Select from the middle of `second` through the end of the blank line after it;
+        // only `second` should be reformatted.
+        let range = Range::new(Position::new(5, 3), Position::new(7, 0));
+        let actual = inner.registry.range_formatting(&url, range).await;
+
+        expect![[r#"
+            Some(
+                [
+                    TextEdit {
+                        range: Range {
+                            start: Position {
+                                line: 5,
+                                character: 0,
+                            },
+                            end: Position {
+                                line: 5,
+                                character: 14,
+                            },
+                        },
+                        new_text: "second = 2",
+                    },
+                ],
+            )
+        "#]]
+        .assert_debug_eq(&actual);
+    }
 }
@@ -72,6 +72,16 @@ pub fn main() -> i32 {
                             println!("{output}");
                         }
                     }
+                    ReplAction::TypeOf {
+                        opt_output,
+                        problems,
+                    } => {
+                        let output = format_output(ANSI_STYLE_CODES, opt_output, problems);
+
+                        if !output.is_empty() {
+                            println!("{output}");
+                        }
+                    }
                     ReplAction::Exit => {
                         return 0;
                     }
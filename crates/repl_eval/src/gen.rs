@@ -5,14 +5,18 @@ use roc_problem::Severity;
 use roc_reporting::report::Palette;
 use std::path::PathBuf;
 
+use roc_collections::MutMap;
 use roc_fmt::annotation::Formattable;
 use roc_fmt::annotation::{Newlines, Parens};
-use roc_load::{LoadingProblem, MonomorphizedModule};
+use roc_load::{LoadedModule, LoadingProblem, MonomorphizedModule};
+use roc_module::symbol::{Interns, ModuleId};
 use roc_parse::ast::Expr;
 use roc_region::all::LineInfo;
 use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
 use roc_solve::FunctionKind;
+use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 
 #[derive(Debug)]
 pub struct ReplOutput {
@@ -79,7 +83,7 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
                 (m.can_problems, m.type_problems)
             );
         }
-        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report, _))) => {
             return (
                 None,
                 Problems {
@@ -101,6 +105,137 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         ..
     } = &mut loaded;
 
+    let problems = collect_problems(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    (Some(loaded), problems)
+}
+
+/// Solves the expression (along with the past defs) and renders its type, without monomorphizing
+/// or evaluating it.
+pub fn compile_to_type<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target_info: TargetInfo,
+    palette: Palette,
+) -> (Option<ReplOutput>, Problems) {
+    let filename = PathBuf::from("replfile.roc");
+    let src_dir = PathBuf::from("fake/test/path");
+    let (bytes_before_expr, module_src) = promote_expr_to_module(arena, defs, expr);
+    let loaded = roc_load::load_and_typecheck_str(
+        arena,
+        filename,
+        module_src,
+        src_dir,
+        target_info,
+        FunctionKind::LambdaSet,
+        roc_reporting::report::RenderTarget::ColorTerminal,
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        palette,
+    );
+
+    let mut loaded = match loaded {
+        Ok(v) => v,
+        Err(problem) => {
+            return (
+                None,
+                Problems {
+                    errors: vec![loading_problem_report(problem, palette)],
+                    warnings: Vec::new(),
+                },
+            );
+        }
+    };
+
+    let LoadedModule {
+        module_id,
+        interns,
+        solved,
+        sources,
+        can_problems,
+        type_problems,
+        exposed_to_host,
+        ..
+    } = &mut loaded;
+
+    let problems = collect_problems(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    debug_assert_eq!(exposed_to_host.len(), 1);
+    let main_fn_var = *exposed_to_host.values().next().unwrap();
+    let expr_type = name_and_print_var(
+        main_fn_var,
+        solved.inner_mut(),
+        *module_id,
+        interns,
+        DebugPrint::NOTHING,
+    );
+
+    let output = ReplOutput {
+        expr: expr.trim().to_string(),
+        expr_type,
+    };
+
+    (Some(output), problems)
+}
+
+/// Renders a problem that stopped the REPL's module from loading at all.
+fn loading_problem_report(problem: LoadingProblem, palette: Palette) -> String {
+    use roc_reporting::report::{to_file_problem_report_string, Report};
+    use ven_pretty::DocAllocator;
+
+    match problem {
+        LoadingProblem::FormattedReport(report, _) => report,
+        LoadingProblem::FileProblem { filename, error } => {
+            to_file_problem_report_string(filename, error)
+        }
+        other => {
+            let interns = Interns::default();
+            let alloc = RocDocAllocator::new(&[], ModuleId::ATTR, &interns);
+
+            let report = Report {
+                title: "LOADING PROBLEM".to_string(),
+                filename: PathBuf::from("replfile.roc"),
+                doc: alloc.stack([
+                    alloc.reflow("I ran into a problem while loading this input:"),
+                    alloc.string(format!("{other:?}")).indent(4),
+                ]),
+                severity: Severity::Fatal,
+            };
+
+            let mut buf = String::new();
+
+            report.render_color_terminal(&mut buf, &alloc, &palette);
+
+            buf
+        }
+    }
+}
+
+fn collect_problems(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    module_src: &str,
+    bytes_before_expr: usize,
+    palette: Palette,
+) -> Problems {
     let mut problems = Problems::default();
 
     let errors = &mut problems.errors;
@@ -164,7 +299,7 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         }
     }
 
-    (Some(loaded), problems)
+    problems
 }
 
 fn promote_expr_to_module<'a, 'i, I: Iterator<Item = &'i str>>(
@@ -4,6 +4,7 @@ use std::{
         atomic::{AtomicBool, AtomicU32},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 use bumpalo::collections::Vec as BumpVec;
@@ -179,6 +180,52 @@ pub fn run_toplevel_expects<'a, W: std::io::Write>(
     )
 }
 
+/// The outcome of running a single top-level `expect`.
+#[derive(Debug)]
+pub struct ToplevelExpectResult {
+    pub symbol: Symbol,
+    pub region: Region,
+    pub passed: bool,
+    pub duration: Duration,
+    /// The rendered failure report; empty if the expect passed.
+    pub output: Vec<u8>,
+}
+
+/// Like [run_toplevel_expects], but instead of writing failures as they happen, returns the
+/// outcome of each expect along with its own rendered report.
+#[allow(clippy::too_many_arguments)]
+pub fn run_toplevel_expects_with_results<'a>(
+    render_target: RenderTarget,
+    arena: &'a Bump,
+    interns: &'a Interns,
+    layout_interner: &GlobalLayoutInterner<'a>,
+    lib: &libloading::Library,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    expects: ExpectFunctions<'_>,
+) -> std::io::Result<Vec<ToplevelExpectResult>> {
+    let shm_name = format!("/roc_expect_buffer_{}", std::process::id());
+    let mut memory = ExpectMemory::create_or_reuse_mmap(&shm_name);
+
+    let mut results = Vec::with_capacity(expects.fx.len() + expects.pure.len());
+
+    run_each_expect(
+        render_target,
+        arena,
+        interns,
+        layout_interner,
+        lib,
+        expectations,
+        expects,
+        &mut memory,
+        |result| {
+            results.push(result);
+            Ok(())
+        },
+    )?;
+
+    Ok(results)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     writer: &mut W,
@@ -194,9 +241,49 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     let mut failed = 0;
     let mut passed = 0;
 
+    run_each_expect(
+        render_target,
+        arena,
+        interns,
+        layout_interner,
+        lib,
+        expectations,
+        expects,
+        memory,
+        |result| {
+            writer.write_all(&result.output)?;
+
+            match result.passed {
+                true => passed += 1,
+                false => failed += 1,
+            }
+
+            Ok(())
+        },
+    )?;
+
+    Ok((failed, passed))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_each_expect<'a>(
+    render_target: RenderTarget,
+    arena: &'a Bump,
+    interns: &'a Interns,
+    layout_interner: &GlobalLayoutInterner<'a>,
+    lib: &libloading::Library,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    expects: ExpectFunctions<'_>,
+    memory: &mut ExpectMemory,
+    mut on_result: impl FnMut(ToplevelExpectResult) -> std::io::Result<()>,
+) -> std::io::Result<()> {
     for expect in expects.fx {
-        let result = run_expect_fx(
-            writer,
+        let mut output = Vec::new();
+        let start = Instant::now();
+        let (symbol, region) = (expect.symbol, expect.region);
+
+        let passed = run_expect_fx(
+            &mut output,
             render_target,
             arena,
             interns,
@@ -207,17 +294,24 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
             expect,
         )?;
 
-        match result {
-            true => passed += 1,
-            false => failed += 1,
-        }
+        on_result(ToplevelExpectResult {
+            symbol,
+            region,
+            passed,
+            duration: start.elapsed(),
+            output,
+        })?;
     }
 
     memory.set_shared_buffer(lib);
 
     for expect in expects.pure {
-        let result = run_expect_pure(
-            writer,
+        let mut output = Vec::new();
+        let start = Instant::now();
+        let (symbol, region) = (expect.symbol, expect.region);
+
+        let passed = run_expect_pure(
+            &mut output,
             render_target,
             arena,
             interns,
@@ -228,13 +322,16 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
             expect,
         )?;
 
-        match result {
-            true => passed += 1,
-            false => failed += 1,
-        }
+        on_result(ToplevelExpectResult {
+            symbol,
+            region,
+            passed,
+            duration: start.elapsed(),
+            output,
+        })?;
     }
 
-    Ok((failed, passed))
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1,9 +1,9 @@
 use bumpalo::Bump;
 use indoc::indoc;
 use roc_repl_cli::{evaluate, ReplHelper};
-use roc_repl_ui::is_incomplete;
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_reporting::report::DEFAULT_PALETTE;
+use roc_repl_ui::{format_output, is_incomplete, TYPE_OF_USAGE};
+use roc_reporting::report::{ANSI_STYLE_CODES, DEFAULT_PALETTE};
 use roc_target::TargetInfo;
 use rustyline::Editor;
 use target_lexicon::Triple;
@@ -148,6 +148,22 @@ fn standalone_annotation() {
     assert!(matches!(action, ReplAction::Nothing));
 }
 
+#[test]
+fn type_of() {
+    let mut state = ReplState::new();
+
+    complete("x = [1, 2]", &mut state, "[1, 2] : List (Num *)");
+    type_of(":type x", &mut state, "x : List (Num *)");
+    type_of(
+        ":type List.map",
+        &mut state,
+        "List.map : List a, (a -> b) -> List b",
+    );
+    type_of(":type\tx", &mut state, "x : List (Num *)");
+    type_of(":type", &mut state, TYPE_OF_USAGE);
+    type_of(":type  ", &mut state, TYPE_OF_USAGE);
+}
+
 /// validate and step the given input, then check the Result vs the output
 /// with ANSI escape codes stripped.
 fn complete(input: &str, state: &mut ReplState, expected_start: &str) {
@@ -177,6 +193,32 @@ fn complete(input: &str, state: &mut ReplState, expected_start: &str) {
     }
 }
 
+/// validate and step the given `:type` input, then check the rendered type vs the output
+/// with ANSI escape codes stripped.
+fn type_of(input: &str, state: &mut ReplState, expected_step_result: &str) {
+    assert!(!is_incomplete(input));
+    let arena = Bump::new();
+    let target = Triple::host();
+    let target_info = TargetInfo::from(&target);
+    let action = state.step(&arena, input, target_info, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::TypeOf {
+            opt_output,
+            problems,
+        } => {
+            let string = format_output(ANSI_STYLE_CODES, opt_output, problems);
+            let escaped =
+                std::string::String::from_utf8(strip_ansi_escapes::strip(string.trim()).unwrap())
+                    .unwrap();
+            assert_eq!(expected_step_result, escaped);
+        }
+        _ => {
+            panic!("Unexpected action: {:?}", action);
+        }
+    }
+}
+
 fn incomplete(input: &mut String) {
     assert!(is_incomplete(input));
 
@@ -48,6 +48,13 @@ pub const TIPS: &str = concatcp!(
             ":help",
             END_COL,
             " shows this text again\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
+            ":type",
+            END_COL,
+            " followed by an expression shows its type without evaluating it\n",
         )
     }
 );
@@ -55,6 +62,11 @@ pub const TIPS: &str = concatcp!(
 // For when nothing is entered in the repl
 // TODO add link to repl tutorial(does not yet exist).
 pub const SHORT_INSTRUCTIONS: &str = "Enter an expression, or :help, or :q to quit.\n\n";
+
+// For when `:type` isn't followed by anything
+pub const TYPE_OF_USAGE: &str =
+    "Enter an expression after :type to see its type, e.g. :type List.map";
+
 pub const PROMPT: &str = concatcp!(CYAN, "»", END_COL, " ");
 pub const CONT_PROMPT: &str = concatcp!(CYAN, "…", END_COL, " ");
 
@@ -77,6 +89,7 @@ pub fn is_incomplete(input: &str) -> bool {
         ParseOutcome::Empty
         | ParseOutcome::Help
         | ParseOutcome::Exit
+        | ParseOutcome::TypeOf(_)
         | ParseOutcome::ValueDef(_)
         | ParseOutcome::TypeDef(_)
         | ParseOutcome::SyntaxErr
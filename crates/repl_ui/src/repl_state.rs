@@ -1,3 +1,4 @@
+use crate::TYPE_OF_USAGE;
 use bumpalo::Bump;
 use roc_collections::MutSet;
 use roc_load::MonomorphizedModule;
@@ -9,7 +10,7 @@ use roc_parse::parser::{EWhen, Either};
 use roc_parse::state::State;
 use roc_parse::{join_alias_to_body, join_ann_to_body};
 use roc_region::all::Loc;
-use roc_repl_eval::gen::{compile_to_mono, Problems};
+use roc_repl_eval::gen::{compile_to_mono, compile_to_type, Problems, ReplOutput};
 use roc_reporting::report::Palette;
 use roc_target::TargetInfo;
 
@@ -37,6 +38,10 @@ pub enum ReplAction<'a> {
         opt_mono: Option<MonomorphizedModule<'a>>,
         problems: Problems,
     },
+    TypeOf {
+        opt_output: Option<ReplOutput>,
+        problems: Problems,
+    },
     Exit,
     Help,
     Nothing,
@@ -61,6 +66,29 @@ impl ReplState {
         let src: &str = match parse_src(arena, line) {
             ParseOutcome::Empty | ParseOutcome::Help => return ReplAction::Help,
             ParseOutcome::Exit => return ReplAction::Exit,
+            ParseOutcome::TypeOf("") => {
+                return ReplAction::TypeOf {
+                    opt_output: None,
+                    problems: Problems {
+                        errors: vec![TYPE_OF_USAGE.to_string()],
+                        warnings: Vec::new(),
+                    },
+                };
+            }
+            ParseOutcome::TypeOf(expr) => {
+                let (opt_output, problems) = compile_to_type(
+                    arena,
+                    self.past_defs.iter().map(|def| def.src.as_str()),
+                    expr,
+                    target_info,
+                    palette,
+                );
+
+                return ReplAction::TypeOf {
+                    opt_output,
+                    problems,
+                };
+            }
             ParseOutcome::Expr(_) | ParseOutcome::Incomplete | ParseOutcome::SyntaxErr => {
                 pending_past_def = None;
 
@@ -190,6 +218,19 @@ impl ReplState {
     }
 }
 
+/// The source of the expression after a `:type` command, if `line` is one.
+/// This is empty when nothing follows `:type`.
+fn type_of_src(line: &str) -> Option<&str> {
+    let rest = line.trim().strip_prefix(":type")?;
+
+    // e.g. `:typo` is not a `:type` command
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ParseOutcome<'a> {
     ValueDef(ValueDef<'a>),
@@ -200,9 +241,15 @@ pub enum ParseOutcome<'a> {
     Empty,
     Help,
     Exit,
+    /// `:type` followed by the source of an expression
+    TypeOf(&'a str),
 }
 
 pub fn parse_src<'a>(arena: &'a Bump, line: &'a str) -> ParseOutcome<'a> {
+    if let Some(expr) = type_of_src(line) {
+        return ParseOutcome::TypeOf(expr);
+    }
+
     match line.trim().to_lowercase().as_str() {
         "" => ParseOutcome::Empty,
         ":help" => ParseOutcome::Help,
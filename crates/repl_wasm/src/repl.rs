@@ -204,6 +204,10 @@ pub async fn entrypoint_from_js(src: String) -> String {
             "To exit the web version of the REPL, just close the browser tab!".to_string()
         }
         ReplAction::Nothing => String::new(),
+        ReplAction::TypeOf {
+            opt_output,
+            problems,
+        } => format_output(HTML_STYLE_CODES, opt_output, problems),
         ReplAction::Eval { opt_mono, problems } => {
             let opt_output = match opt_mono {
                 Some(mono) => eval_wasm(arena, target_info, mono).await,
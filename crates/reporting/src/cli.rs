@@ -3,10 +3,13 @@ use std::path::PathBuf;
 use roc_collections::MutMap;
 use roc_module::symbol::{Interns, ModuleId};
 use roc_problem::can::Problem;
-use roc_region::all::LineInfo;
+use roc_region::all::{LineColumnRegion, LineInfo};
 use roc_solve_problem::TypeError;
 
-use crate::report::ANSI_STYLE_CODES;
+use crate::report::{
+    can_problem, strip_colors, type_problem, Report, ReportSummary, RocDocAllocator,
+    ANSI_STYLE_CODES,
+};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Problems {
@@ -66,82 +69,24 @@ pub fn report_problems(
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
 ) -> Problems {
-    use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
+    use crate::report::DEFAULT_PALETTE;
     use roc_problem::Severity::*;
 
     let palette = DEFAULT_PALETTE;
-    let mut total_problems = 0;
-
-    for problems in can_problems.values() {
-        total_problems += problems.len();
-    }
-
-    for problems in type_problems.values() {
-        total_problems += problems.len();
-    }
-
-    // This will often over-allocate total memory, but it means we definitely
-    // never need to re-allocate either the warnings or the errors vec!
-    let mut warnings = Vec::with_capacity(total_problems);
-    let mut errors = Vec::with_capacity(total_problems);
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
     let mut fatally_errored = false;
 
-    for (home, (module_path, src)) in sources.iter() {
-        let mut src_lines: Vec<&str> = Vec::new();
-
-        src_lines.extend(src.split('\n'));
-
-        let lines = LineInfo::new(&src_lines.join("\n"));
-
-        // Report parsing and canonicalization problems
-        let alloc = RocDocAllocator::new(&src_lines, *home, interns);
-
-        let problems = type_problems.remove(home).unwrap_or_default();
-
-        for problem in problems {
-            if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
-                let severity = report.severity;
-                let mut buf = String::new();
-
-                report.render_color_terminal(&mut buf, &alloc, &palette);
-
-                match severity {
-                    Warning => {
-                        warnings.push(buf);
-                    }
-                    RuntimeError => {
-                        errors.push(buf);
-                    }
-                    Fatal => {
-                        fatally_errored = true;
-                        errors.push(buf);
-                    }
-                }
-            }
-        }
-
-        // Shadowing errors often cause cryptic type errors. To make it easy to spot the root cause,
-        // we print the shadowing errors last.
-        let problems = can_problems.remove(home).unwrap_or_default();
-        let (shadowing_errs, mut ordered): (Vec<Problem>, Vec<Problem>) =
-            problems.into_iter().partition(|p| {
-                matches!(
-                    p,
-                    Problem::Shadowing {
-                        original_region: _,
-                        shadow: _,
-                        kind: _,
-                    }
-                )
-            });
-        ordered.extend(shadowing_errs);
-
-        for problem in ordered.into_iter() {
-            let report = can_problem(&alloc, &lines, module_path.clone(), problem);
+    for_each_report(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        |report, alloc, _code, _region, _related| {
             let severity = report.severity;
             let mut buf = String::new();
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            report.render_color_terminal(&mut buf, alloc, &DEFAULT_PALETTE);
 
             match severity {
                 Warning => {
@@ -155,11 +100,8 @@ pub fn report_problems(
                     errors.push(buf);
                 }
             }
-        }
-    }
-
-    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
-    debug_assert_eq!(errors.len() + warnings.len(), total_problems);
+        },
+    );
 
     let problems_reported;
 
@@ -194,3 +136,225 @@ pub fn report_problems(
         warnings: warnings.len(),
     }
 }
+
+/// Like [report_problems], but prints every problem (warnings included) as
+/// one JSON array, so editors and CI tools don't have to scrape the
+/// human-readable output. See [problems_to_json] for the format.
+pub fn report_problems_json(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+) -> Problems {
+    let diagnostics = problems_to_json(sources, interns, can_problems, type_problems);
+    let problems = count_json_problems(&diagnostics);
+
+    println!("{}", serde_json::Value::Array(diagnostics));
+
+    problems
+}
+
+/// Counts the errors and warnings in the output of [problems_to_json].
+pub fn count_json_problems(diagnostics: &[serde_json::Value]) -> Problems {
+    let mut problems = Problems::default();
+
+    for diagnostic in diagnostics {
+        match diagnostic["severity"].as_str() {
+            Some("warning") => problems.warnings += 1,
+            Some("fatal") => {
+                problems.fatally_errored = true;
+                problems.errors += 1;
+            }
+            _ => problems.errors += 1,
+        }
+    }
+
+    problems
+}
+
+/// Converts every problem into a JSON object like
+///
+/// ```json
+/// {
+///   "severity": "error",
+///   "code": "type-mismatch",
+///   "title": "TYPE MISMATCH",
+///   "file": "main.roc",
+///   "region": { "start": { "line": 4, "column": 8 }, "end": { "line": 4, "column": 11 } },
+///   "related": [],
+///   "message": "..."
+/// }
+/// ```
+///
+/// Lines and columns are zero-based. `region` is `null` for problems that
+/// don't point at a single place in the source. `related` holds the other
+/// places the problem points at, e.g. the original definition of a shadowed name.
+pub fn problems_to_json(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+) -> Vec<serde_json::Value> {
+    use roc_problem::Severity::*;
+    use serde_json::json;
+
+    let mut diagnostics = Vec::new();
+
+    for_each_report(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        |mut report, alloc, code, region, related| {
+            let severity = match report.severity {
+                Warning => "warning",
+                RuntimeError => "error",
+                Fatal => "fatal",
+            };
+            let title = std::mem::take(&mut report.title);
+            let file = report.filename.display().to_string();
+            let mut message = String::new();
+
+            report.render_ci(&mut message, alloc);
+
+            let related: Vec<_> = related.into_iter().map(region_to_json).collect();
+
+            diagnostics.push(json!({
+                "severity": severity,
+                "code": code,
+                "title": title,
+                "file": file,
+                "region": region.map(region_to_json),
+                "related": related,
+                "message": message,
+            }));
+        },
+    );
+
+    diagnostics
+}
+
+/// Converts a problem that stopped the compiler from loading the program at all,
+/// e.g. a syntax error, into the same JSON format as [problems_to_json].
+///
+/// `message` is the rendered report without colors. Reports that come without a
+/// summary, e.g. failed package downloads, only have a `code` of `loading-problem`
+/// and a `message`.
+pub fn loading_problem_to_json(report: &str, summary: Option<&ReportSummary>) -> serde_json::Value {
+    use serde_json::json;
+
+    let message = strip_colors(report);
+
+    json!({
+        "severity": "fatal",
+        "code": summary.map_or("loading-problem", |summary| summary.code),
+        "title": summary.map(|summary| &summary.title),
+        "file": summary.map(|summary| summary.filename.display().to_string()),
+        "region": summary.and_then(|summary| summary.region).map(region_to_json),
+        "related": [],
+        "message": message.trim(),
+    })
+}
+
+fn region_to_json(region: LineColumnRegion) -> serde_json::Value {
+    serde_json::json!({
+        "start": { "line": region.start.line, "column": region.start.column },
+        "end": { "line": region.end.line, "column": region.end.column },
+    })
+}
+
+/// Turns every problem into a [Report] and hands it to `f`, along with the
+/// problem's code, its region (if it has one) and its related regions, converted to
+/// lines and columns.
+fn for_each_report<F>(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    mut f: F,
+) where
+    F: for<'b> FnMut(
+        Report<'b>,
+        &'b RocDocAllocator<'b>,
+        &'static str,
+        Option<LineColumnRegion>,
+        Vec<LineColumnRegion>,
+    ),
+{
+    let mut total_problems = 0;
+    let mut reports = 0;
+
+    for problems in can_problems.values() {
+        total_problems += problems.len();
+    }
+
+    for problems in type_problems.values() {
+        total_problems += problems.len();
+    }
+
+    // Report modules in path order, so the output doesn't depend on the order modules were loaded.
+    let mut sources: Vec<_> = sources.iter().collect();
+
+    sources.sort_by_key(|(_, (module_path, _))| module_path);
+
+    for (home, (module_path, src)) in sources {
+        let mut src_lines: Vec<&str> = Vec::new();
+
+        src_lines.extend(src.split('\n'));
+
+        let lines = LineInfo::new(&src_lines.join("\n"));
+
+        // Report parsing and canonicalization problems
+        let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+
+        let problems = type_problems.remove(home).unwrap_or_default();
+
+        for problem in problems {
+            let code = problem.code();
+            let region = problem.region().map(|region| lines.convert_region(region));
+
+            if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
+                reports += 1;
+                f(report, &alloc, code, region, Vec::new());
+            }
+        }
+
+        // Shadowing errors often cause cryptic type errors. To make it easy to spot the root cause,
+        // we print the shadowing errors last.
+        let problems = can_problems.remove(home).unwrap_or_default();
+        let (shadowing_errs, mut ordered): (Vec<Problem>, Vec<Problem>) =
+            problems.into_iter().partition(|p| {
+                matches!(
+                    p,
+                    Problem::Shadowing {
+                        original_region: _,
+                        shadow: _,
+                        kind: _,
+                    }
+                )
+            });
+        ordered.extend(shadowing_errs);
+
+        for problem in ordered.into_iter() {
+            let code = problem.code();
+            let region = problem.region().map(|region| lines.convert_region(region));
+            let related = problem
+                .related_regions()
+                .into_iter()
+                .map(|region| lines.convert_region(region))
+                .collect();
+
+            reports += 1;
+            f(
+                can_problem(&alloc, &lines, module_path.clone(), problem),
+                &alloc,
+                code,
+                region,
+                related,
+            );
+        }
+    }
+
+    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
+    debug_assert_eq!(reports, total_problems);
+}
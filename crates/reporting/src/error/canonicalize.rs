@@ -147,18 +147,25 @@ pub fn can_problem<'b>(
 
             title = "DEFINITIONs ONLY USED IN RECURSION".to_string();
         }
-        Problem::ExposedButNotDefined(symbol) => {
-            doc = alloc.stack([
-                alloc.symbol_unqualified(symbol).append(
-                    alloc.reflow(" is listed as exposed, but it isn't defined in this module."),
-                ),
+        Problem::ExposedButNotDefined(symbol, opt_region) => {
+            let mut stack = vec![alloc.symbol_unqualified(symbol).append(
+                alloc.reflow(" is listed as exposed, but it isn't defined in this module."),
+            )];
+
+            if let Some(region) = opt_region {
+                stack.push(alloc.region(lines.convert_region(region)));
+            }
+
+            stack.push(
                 alloc
                     .reflow("You can fix this by adding a definition for ")
                     .append(alloc.symbol_unqualified(symbol))
                     .append(alloc.reflow(", or by removing it from "))
                     .append(alloc.keyword("exposes"))
                     .append(alloc.reflow(".")),
-            ]);
+            );
+
+            doc = alloc.stack(stack);
 
             title = MISSING_DEFINITION.to_string();
         }
@@ -231,6 +238,8 @@ pub fn can_problem<'b>(
             title = UNUSED_DEF.to_string();
         }
         Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op)) => {
+            let region = lines.convert_region(region);
+
             doc = alloc.stack([
                 if left_bin_op.value == right_bin_op.value {
                     alloc.concat([
@@ -253,7 +262,18 @@ pub fn can_problem<'b>(
                         )),
                     ])
                 },
-                alloc.region(lines.convert_region(region)),
+                if region.start().line != region.end().line {
+                    // mark every line of a multi-line chain rather than underlining
+                    // operators that may be far apart
+                    alloc.region(region)
+                } else {
+                    alloc.region_all_the_things(
+                        region,
+                        lines.convert_region(left_bin_op.region),
+                        lines.convert_region(right_bin_op.region),
+                        Annotation::Error,
+                    )
+                },
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
@@ -2202,6 +2222,32 @@ fn pretty_runtime_error<'b>(
 
             title = "UNAPPLIED RECORD BUILDER";
         }
+        RuntimeError::MisplacedTrySuffix(region) => {
+            let tip = alloc
+                .tip()
+                .append(alloc.reflow("Give the result a name, like "))
+                .append(alloc.parser_suggestion("x = foo?"))
+                .append(alloc.reflow(", and use that name instead."));
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.parser_suggestion("?"),
+                    alloc.reflow(" is not the whole body of a definition:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("A "),
+                    alloc.parser_suggestion("?"),
+                    alloc.reflow(" can only be used on the right side of a definition inside a block, so the rest of the block can be skipped when the result is an "),
+                    alloc.tag("Err".into()),
+                    alloc.reflow("."),
+                ]),
+                tip,
+            ]);
+
+            title = "MISPLACED TRY";
+        }
     }
 
     (doc, title)
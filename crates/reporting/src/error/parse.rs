@@ -687,6 +687,9 @@ fn to_expr_report<'a>(
                 severity: Severity::RuntimeError,
             }
         }
+        EExpr::TooDeep(pos) => {
+            to_too_deep_report(alloc, lines, filename, "an expression", start, *pos)
+        }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
     }
 }
@@ -1260,12 +1263,13 @@ fn to_expr_in_parens_report<'a>(
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
 
             let doc = alloc.stack([
-                alloc
-                    .reflow("I am partway through parsing a record pattern, but I got stuck here:"),
+                alloc.reflow(
+                    "I am partway through parsing a parenthesized expression or tuple, but I got stuck here:",
+                ),
                 alloc.region_with_subregion(lines.convert_region(surroundings), region),
                 alloc.concat([
                     alloc.reflow(
-                        r"I was expecting to see a closing parenthesis next, so try adding a ",
+                        r"I was expecting to see a closing parenthesis to match the opening one, so try adding a ",
                     ),
                     alloc.parser_suggestion(")"),
                     alloc.reflow(" and see if that helps?"),
@@ -1878,6 +1882,9 @@ fn to_pattern_report<'a>(
         &EPattern::NumLiteral(ENumber::End, pos) => {
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
+        EPattern::TooDeep(pos) => {
+            to_too_deep_report(alloc, lines, filename, "a pattern", start, *pos)
+        }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
     }
 }
@@ -2378,10 +2385,44 @@ fn to_type_report<'a>(
             }
         }
 
+        EType::TTooDeep(pos) => to_too_deep_report(alloc, lines, filename, "a type", start, *pos),
+
         _ => todo!("unhandled type parse error: {:?}", &parse_problem),
     }
 }
 
+fn to_too_deep_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    what: &'static str,
+    start: Position,
+    pos: Position,
+) -> Report<'a> {
+    let surroundings = Region::new(start, pos);
+    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("I am partway through parsing "),
+            alloc.reflow(what),
+            alloc.reflow(", but it is nested too deeply:"),
+        ]),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region),
+        alloc.concat([
+            alloc.reflow("I gave up here, so that I would not run out of stack space. "),
+            alloc.reflow("Try pulling some of the inner parts out into their own definitions."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "NESTED TOO DEEPLY".to_string(),
+        severity: Severity::RuntimeError,
+    }
+}
+
 fn to_trecord_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
@@ -2,7 +2,7 @@ use roc_module::ident::Ident;
 use roc_module::ident::{Lowercase, ModuleName, TagName, Uppercase};
 use roc_module::symbol::{Interns, ModuleId, ModuleIds, PQModuleName, PackageQualified, Symbol};
 use roc_problem::Severity;
-use roc_region::all::LineColumnRegion;
+use roc_region::all::{LineColumn, LineColumnRegion};
 use std::path::{Path, PathBuf};
 use std::{fmt, io};
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
@@ -122,7 +122,27 @@ pub struct Report<'b> {
     pub severity: Severity,
 }
 
+/// The parts of a [Report] that tools like `roc check --json` care about,
+/// kept alongside the rendered text so they don't have to be scraped back out of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportSummary {
+    /// A stable identifier for the kind of problem, e.g. `syntax-error`
+    pub code: &'static str,
+    pub title: String,
+    pub filename: PathBuf,
+    pub region: Option<LineColumnRegion>,
+}
+
 impl<'b> Report<'b> {
+    pub fn summary(&self, code: &'static str, region: Option<LineColumnRegion>) -> ReportSummary {
+        ReportSummary {
+            code,
+            title: self.title.clone(),
+            filename: self.filename.clone(),
+            region,
+        }
+    }
+
     pub fn render(
         self,
         target: RenderTarget,
@@ -341,6 +361,31 @@ impl<'a> RocDocAllocator<'a> {
         }
     }
 
+    /// Regions count columns in bytes, but a terminal renders one cell per `char`.
+    /// Convert a region to display columns, so the `^^^` underline lines up with
+    /// source lines that contain multibyte characters.
+    fn display_region(&self, region: LineColumnRegion) -> LineColumnRegion {
+        LineColumnRegion::new(
+            self.display_column(region.start),
+            self.display_column(region.end),
+        )
+    }
+
+    fn display_column(&self, lc: LineColumn) -> LineColumn {
+        let line = self.src_lines.get(lc.line as usize).unwrap_or(&"");
+
+        let column = match line.get(..lc.column as usize) {
+            Some(before) => before.chars().count() as u32,
+            // The column is past the end of the line, or not on a char boundary.
+            None => lc.column,
+        };
+
+        LineColumn {
+            line: lc.line,
+            column,
+        }
+    }
+
     /// vertical concatenation. Adds a newline between elements
     pub fn vcat<A, I>(&'a self, docs: I) -> DocBuilder<'a, Self, A>
     where
@@ -609,6 +654,8 @@ impl<'a> RocDocAllocator<'a> {
         }
 
         if error_highlight_line {
+            let sub_region1 = self.display_region(sub_region1);
+            let sub_region2 = self.display_region(sub_region2);
             let overlapping = sub_region2.start().column < sub_region1.end().column;
 
             let highlight = if overlapping {
@@ -742,6 +789,7 @@ impl<'a> RocDocAllocator<'a> {
         }
 
         if error_highlight_line {
+            let sub_region = self.display_region(sub_region);
             let highlight_text = ERROR_UNDERLINE
                 .repeat((sub_region.end().column - sub_region.start().column) as usize);
 
@@ -1594,6 +1642,13 @@ pub fn to_https_problem_report<'b>(
 }
 
 pub fn to_file_problem_report_string(filename: PathBuf, error: io::ErrorKind) -> String {
+    to_file_problem_report_string_with_summary(filename, error).0
+}
+
+pub fn to_file_problem_report_string_with_summary(
+    filename: PathBuf,
+    error: io::ErrorKind,
+) -> (String, ReportSummary) {
     let src_lines: Vec<&str> = Vec::new();
     let mut module_ids = ModuleIds::default();
     let module_id = module_ids.get_or_insert(&"find module name somehow?".into());
@@ -1605,9 +1660,10 @@ pub fn to_file_problem_report_string(filename: PathBuf, error: io::ErrorKind) ->
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     let report = to_file_problem_report(&alloc, filename, error);
+    let summary = report.summary("file-problem", None);
     report.render_color_terminal(&mut buf, &alloc, &palette);
 
-    buf
+    (buf, summary)
 }
 
 pub fn to_file_problem_report<'b>(